@@ -0,0 +1,108 @@
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use aoc_input::Source;
+use common::{PartResult, Solution};
+use crate::with_day;
+
+/// The spread of a set of timings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort_unstable();
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Timings for a single day. Parts that are not implemented have no
+/// timings.
+#[derive(Debug, Clone)]
+pub struct Timings {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+fn time_runs<T, F: FnMut() -> T>(runs: usize, mut f: F) -> Vec<Duration> {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect()
+}
+
+/// Times parsing and each part separately, `runs` times each. The
+/// parts all work on the result of a single parse.
+pub fn bench<S: Solution>(day: u8, input: &Source, runs: usize) -> aoc_input::Result<Timings> {
+    assert!(runs > 0);
+    let parsed = S::parse(input)?;
+    let parse = Stats::from_samples(time_runs(runs, || S::parse(black_box(input))));
+
+    //  A part that is not implemented returns None straight away, so
+    //  there is no point timing it, and one that fails is reported
+    let time_part = |part: fn(&S::Input) -> PartResult| match part(&parsed) {
+        Ok(Some(_)) => Ok(Some(Stats::from_samples(time_runs(runs, || part(black_box(&parsed)))))),
+        Ok(None) => Ok(None),
+        Err(err) => Err(err.in_file(input.name())),
+    };
+    let part1 = time_part(S::part1)?;
+    let part2 = time_part(S::part2)?;
+
+    Ok(Timings { day, parse, part1, part2 })
+}
+
+/// Dispatches to the solution for the given day, returning None if
+/// there is no such day.
+pub fn bench_day(day: u8, input: &Source, runs: usize) -> Option<aoc_input::Result<Timings>> {
+    with_day!(day, bench(day, input, runs))
+}
+
+fn stats_json(stats: &Option<Stats>) -> String {
+    match stats {
+        Some(stats) => format!(
+            "{{\"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.max.as_nanos()
+        ),
+        None => "null".to_string(),
+    }
+}
+
+/// A JSON report of the timings, with all durations in nanoseconds
+pub fn to_json(timings: &[Timings], runs: usize) -> String {
+    let mut output = String::new();
+    writeln!(output, "{{").unwrap();
+    writeln!(output, "  \"runs\": {},", runs).unwrap();
+    writeln!(output, "  \"days\": [").unwrap();
+    for (idx, day) in timings.iter().enumerate() {
+        let separator = if idx + 1 < timings.len() { "," } else { "" };
+        writeln!(
+            output,
+            "    {{\"day\": {}, \"parse\": {}, \"part1\": {}, \"part2\": {}}}{}",
+            day.day,
+            stats_json(&Some(day.parse)),
+            stats_json(&day.part1),
+            stats_json(&day.part2),
+            separator
+        )
+        .unwrap();
+    }
+    writeln!(output, "  ]").unwrap();
+    writeln!(output, "}}").unwrap();
+
+    output
+}
@@ -0,0 +1,118 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+use aoc_input::{Error, ErrorKind, Source};
+
+const YEAR: u32 = 2021;
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Puzzle inputs kept in a local directory, downloaded from the server
+/// the first time each day is asked for.
+///
+/// Inputs are fetched from `{base_url}/2021/day/{day}/input`, using the
+/// session token as the `session` cookie.
+#[derive(Debug, Clone)]
+pub struct InputCache {
+    dir: PathBuf,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl InputCache {
+    pub fn new<P: Into<PathBuf>>(dir: P, base_url: &str) -> InputCache {
+        InputCache {
+            dir: dir.into(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: None,
+        }
+    }
+
+    /// Configured through `AOC_CACHE_DIR`, `AOC_BASE_URL` and `AOC_SESSION`.
+    /// The cache defaults to `.inputs` in the workspace.
+    pub fn from_env() -> InputCache {
+        let dir = env::var_os("AOC_CACHE_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| [env!("CARGO_MANIFEST_DIR"), "..", ".inputs"].iter().collect());
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+
+        let cache = InputCache::new(dir, &base_url);
+        match env::var("AOC_SESSION") {
+            Ok(session) if !session.trim().is_empty() => cache.with_session(session.trim()),
+            _ => cache,
+        }
+    }
+
+    pub fn with_session(mut self, session: &str) -> InputCache {
+        self.session = Some(session.to_string());
+        self
+    }
+
+    /// Where the input for a day is stored
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{}.txt", day))
+    }
+
+    pub fn url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, YEAR, day)
+    }
+
+    /// The input for a day, downloading and storing it if it is not in
+    /// the cache yet
+    pub fn get(&self, day: u8) -> aoc_input::Result<Source> {
+        let path = self.path(day);
+        if path.is_file() {
+            return Source::open(path);
+        }
+
+        let text = self.fetch(day)?;
+        let name = path.display().to_string();
+        let stored = fs::create_dir_all(&self.dir).and_then(|_| fs::write(&path, &text));
+        if let Err(err) = stored {
+            return Err(Error::new(&name, 0, 0, ErrorKind::Io(err.to_string())));
+        }
+
+        Ok(Source::new(&name, &text))
+    }
+
+    /// Downloads the input for a day, bypassing the cache
+    pub fn fetch(&self, day: u8) -> aoc_input::Result<String> {
+        let url = self.url(day);
+        let error = |message: String| Error::new(&url, 0, 0, ErrorKind::Io(message));
+
+        let session = self
+            .session
+            .as_ref()
+            .ok_or_else(|| error("no session token, set AOC_SESSION to download inputs".to_string()))?;
+
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .build();
+        let response = agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", "aoc-2021 input cache")
+            .call();
+
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|err| error(err.to_string())),
+            Err(ureq::Error::Status(code, response)) => Err(error(format!(
+                "server responded with {} {}",
+                code,
+                response.status_text()
+            ))),
+            Err(ureq::Error::Transport(transport)) => {
+                let mut message = transport.kind().to_string();
+                if let Some(detail) = transport.message() {
+                    message = format!("{}: {}", message, detail);
+                }
+                if let Some(source) = std::error::Error::source(&transport) {
+                    message = format!("{}: {}", message, source);
+                }
+                Err(error(message))
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+use aoc_input::Source;
+use common::{Answer, Solution};
+
+pub mod bench;
+pub mod cache;
+
+/// Every day that has a solution, in calendar order
+pub const DAYS: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22];
+
+/// The `input.txt` of a day, found relative to the workspace rather
+/// than the current directory
+pub fn default_input(day: u8) -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "..", &format!("ch{}", day), "input.txt"]
+        .iter()
+        .collect()
+}
+
+/// Calls a function generic over `Solution` with the solution for the
+/// given day, evaluating to None if there is no such day.
+macro_rules! with_day {
+    ($day:expr, $func:ident($($arg:expr),*)) => {
+        match $day {
+            1 => Some($func::<ch1::Day1>($($arg),*)),
+            2 => Some($func::<ch2::Day2>($($arg),*)),
+            3 => Some($func::<ch3::Day3>($($arg),*)),
+            4 => Some($func::<ch4::Day4>($($arg),*)),
+            5 => Some($func::<ch5::Day5>($($arg),*)),
+            6 => Some($func::<ch6::Day6>($($arg),*)),
+            7 => Some($func::<ch7::Day7>($($arg),*)),
+            8 => Some($func::<ch8::Day8>($($arg),*)),
+            9 => Some($func::<ch9::Day9>($($arg),*)),
+            10 => Some($func::<ch10::Day10>($($arg),*)),
+            11 => Some($func::<ch11::Day11>($($arg),*)),
+            12 => Some($func::<ch12::Day12>($($arg),*)),
+            13 => Some($func::<ch13::Day13>($($arg),*)),
+            14 => Some($func::<ch14::Day14>($($arg),*)),
+            15 => Some($func::<ch15::Day15>($($arg),*)),
+            16 => Some($func::<ch16::Day16>($($arg),*)),
+            17 => Some($func::<ch17::Day17>($($arg),*)),
+            18 => Some($func::<ch18::Day18>($($arg),*)),
+            19 => Some($func::<ch19::Day19>($($arg),*)),
+            20 => Some($func::<ch20::Day20>($($arg),*)),
+            21 => Some($func::<ch21::Day21>($($arg),*)),
+            22 => Some($func::<ch22::Day22>($($arg),*)),
+            _ => None,
+        }
+    };
+}
+
+pub(crate) use with_day;
+
+/// The answer to each part that was run. Each is `None` if that part has
+/// not been implemented, or an error if it can't answer for the input.
+pub type Answers = Vec<aoc_input::Result<Option<Answer>>>;
+
+/// Parses the input once and runs the requested parts of a single day
+pub fn solve<S: Solution>(input: &Source, parts: &[u8]) -> aoc_input::Result<Answers> {
+    let parsed = S::parse(input)?;
+
+    Ok(parts
+        .iter()
+        .map(|&part| match part {
+            1 => S::part1(&parsed),
+            _ => S::part2(&parsed),
+        })
+        .map(|answer| answer.map_err(|err| err.in_file(input.name())))
+        .collect())
+}
+
+/// Dispatches to the solution for the given day, returning None if
+/// there is no such day.
+pub fn solve_day(day: u8, input: &Source, parts: &[u8]) -> Option<aoc_input::Result<Answers>> {
+    with_day!(day, solve(input, parts))
+}
@@ -0,0 +1,215 @@
+use std::env;
+use std::fs;
+use std::process::ExitCode;
+use std::str::FromStr;
+use aoc::bench::{Stats, Timings};
+use aoc::cache::InputCache;
+use aoc_input::Source;
+
+struct Args {
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<String>,
+    bench: bool,
+    runs: usize,
+    report: Option<String>,
+}
+
+fn usage() -> &'static str {
+    "usage: aoc --day N [--part {1,2}] [--input PATH|-]\n       \
+     aoc --bench [--day N] [--input PATH] [--runs N] [--report PATH]"
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut day: Option<u8> = None;
+    let mut part: Option<u8> = None;
+    let mut input: Option<String> = None;
+    let mut bench = false;
+    let mut runs: usize = 10;
+    let mut report: Option<String> = None;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let value = match arg.as_str() {
+            "--day" | "--part" | "--input" | "--runs" | "--report" => args
+                .next()
+                .ok_or_else(|| format!("missing value for '{}'", arg))?,
+            "--bench" => {
+                bench = true;
+                continue;
+            }
+            "-h" | "--help" => return Err(usage().to_string()),
+            _ => return Err(format!("unknown argument '{}'", arg)),
+        };
+
+        match arg.as_str() {
+            "--day" => {
+                day = Some(u8::from_str(&value).map_err(|_| format!("invalid day '{}'", value))?);
+            }
+            "--part" => {
+                let value = u8::from_str(&value).map_err(|_| format!("invalid part '{}'", value))?;
+                if value != 1 && value != 2 {
+                    return Err(format!("part must be 1 or 2, got {}", value));
+                }
+                part = Some(value);
+            }
+            "--runs" => {
+                runs = usize::from_str(&value)
+                    .ok()
+                    .filter(|&runs| runs > 0)
+                    .ok_or_else(|| format!("invalid number of runs '{}'", value))?;
+            }
+            "--report" => {
+                report = Some(value);
+            }
+            _ => {
+                input = Some(value);
+            }
+        }
+    }
+
+    //  Benchmarking every day needs each day's own input
+    if day.is_none() && (!bench || input.is_some()) {
+        return Err(usage().to_string());
+    }
+    Ok(Args { day, part, input, bench, runs, report })
+}
+
+/// Opens the input given on the command line, or else the day's own
+/// `input.txt`, falling back to the input cache if there is none.
+fn find_input(day: u8, input: &Option<String>) -> aoc_input::Result<Source> {
+    match input {
+        Some(arg) => Source::from_arg(arg),
+        None if aoc::default_input(day).is_file() => Source::open(aoc::default_input(day)),
+        None => InputCache::from_env().get(day),
+    }
+}
+
+fn open_input(day: u8, input: &Option<String>) -> Result<Source, ExitCode> {
+    find_input(day, input).map_err(|err| {
+        eprintln!("{}", err);
+        ExitCode::FAILURE
+    })
+}
+
+fn run(args: &Args) -> Result<(), ExitCode> {
+    let day = args.day.unwrap();
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let input = open_input(day, &args.input)?;
+    let answers = match aoc::solve_day(day, &input, &parts) {
+        Some(Ok(answers)) => answers,
+        Some(Err(err)) => {
+            eprintln!("{}", err);
+            return Err(ExitCode::FAILURE);
+        }
+        None => {
+            eprintln!("Day {} is not implemented", day);
+            return Err(ExitCode::FAILURE);
+        }
+    };
+
+    let mut status = Ok(());
+    for (part, answer) in parts.iter().zip(answers) {
+        match answer {
+            Ok(Some(answer)) => println!("Day {} part {}: {}", day, part, answer),
+            Ok(None) => {
+                eprintln!("Day {} part {} is not implemented", day, part);
+                status = Err(ExitCode::FAILURE);
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+                status = Err(ExitCode::FAILURE);
+            }
+        }
+    }
+
+    status
+}
+
+fn print_stats(day: u8, name: &str, stats: &Option<Stats>) {
+    match stats {
+        Some(stats) => println!(
+            "Day {:>2} {:<5}  min {:>12?}  median {:>12?}  max {:>12?}",
+            day, name, stats.min, stats.median, stats.max
+        ),
+        None => println!("Day {:>2} {:<5}  not implemented", day, name),
+    }
+}
+
+fn run_bench(args: &Args) -> Result<(), ExitCode> {
+    let days: Vec<u8> = match args.day {
+        Some(day) => vec![day],
+        None => aoc::DAYS.to_vec(),
+    };
+
+    //  When going through every day, one that can't be run is reported
+    //  and skipped rather than stopping the others from being timed
+    let mut status = Ok(());
+    let mut timings: Vec<Timings> = vec![];
+    for day in days {
+        let input = match find_input(day, &args.input) {
+            Ok(input) => input,
+            Err(err) if args.day.is_none() => {
+                eprintln!("Day {:>2} skipped, no input: {}", day, err);
+                continue;
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+                return Err(ExitCode::FAILURE);
+            }
+        };
+
+        let result = match aoc::bench::bench_day(day, &input, args.runs) {
+            Some(Ok(result)) => result,
+            Some(Err(err)) => {
+                eprintln!("{}", err);
+                status = Err(ExitCode::FAILURE);
+                continue;
+            }
+            None => {
+                eprintln!("Day {} is not implemented", day);
+                status = Err(ExitCode::FAILURE);
+                continue;
+            }
+        };
+
+        print_stats(day, "parse", &Some(result.parse));
+        print_stats(day, "part1", &result.part1);
+        print_stats(day, "part2", &result.part2);
+        timings.push(result);
+    }
+
+    if let Some(report) = &args.report {
+        if let Err(err) = fs::write(report, aoc::bench::to_json(&timings, args.runs)) {
+            eprintln!("Failed to write '{}': {}", report, err);
+            return Err(ExitCode::FAILURE);
+        }
+    }
+
+    status
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = if args.bench {
+        run_bench(&args)
+    } else {
+        run(&args)
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(status) => status,
+    }
+}
@@ -0,0 +1,56 @@
+//  Checks each day's input.txt against the answers recorded in
+//  answers.txt at the root of the workspace, or in the file named by
+//  AOC_ANSWERS. Days without a recorded answer, or without the file
+//  at all, are skipped.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use aoc_input::Source;
+
+fn workspace_path(file: &str) -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "..", file].iter().collect()
+}
+
+/// Reads `day part answer` lines, skipping blanks and `#` comments
+fn load_answers(text: &str) -> Vec<(u8, u8, String)> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut fields = line.splitn(3, ' ');
+            let mut field = || fields.next().unwrap_or_else(|| panic!("malformed answer '{}'", line));
+            let day = field().parse().unwrap();
+            let part = field().parse().unwrap();
+            let answer = field().replace("\\n", "\n");
+            (day, part, answer)
+        })
+        .collect()
+}
+
+#[test]
+fn recorded_answers() {
+    let path = env::var("AOC_ANSWERS")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| workspace_path("answers.txt"));
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(_) => {
+            eprintln!("no answers at {}, skipping", path.display());
+            return;
+        }
+    };
+
+    for (day, part, expected) in load_answers(&text) {
+        let input = Source::open(workspace_path(&format!("ch{}/input.txt", day))).unwrap();
+        let answers = aoc::solve_day(day, &input, &[part])
+            .unwrap_or_else(|| panic!("no such day {}", day))
+            .unwrap_or_else(|err| panic!("{}", err));
+
+        let answer = match &answers[0] {
+            Ok(answer) => answer.as_ref().map(|answer| answer.to_string()),
+            Err(err) => panic!("{}", err),
+        };
+        assert_eq!(answer.as_deref(), Some(expected.as_str()), "day {} part {}", day, part);
+    }
+}
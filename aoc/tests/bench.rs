@@ -0,0 +1,19 @@
+use std::path::PathBuf;
+use aoc_input::Source;
+
+#[test]
+fn bench_report() {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "..", "ch4", "sample.txt"].iter().collect();
+    let input = Source::open(path).unwrap();
+
+    let timings = aoc::bench::bench_day(4, &input, 5).unwrap().unwrap();
+    assert_eq!(timings.day, 4);
+    assert!(timings.parse.min <= timings.parse.median && timings.parse.median <= timings.parse.max);
+    assert!(timings.part1.is_some());
+    assert!(timings.part2.is_some());
+
+    let report = aoc::bench::to_json(&[timings], 5);
+    assert!(report.contains("\"runs\": 5"));
+    assert!(report.contains("{\"day\": 4, \"parse\": {\"min_ns\": "));
+    assert!(report.contains("\"part2\": {\"min_ns\": "));
+}
@@ -0,0 +1,130 @@
+//  Exercises the input cache against a small stand-in for the puzzle
+//  server, which serves any day's input to requests with the right
+//  session cookie.
+
+use std::env;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use aoc::cache::InputCache;
+
+const SESSION: &str = "0123abcd";
+
+struct Server {
+    base_url: String,
+    requests: Arc<AtomicUsize>,
+}
+
+fn input_for(day: &str) -> String {
+    format!("input for day {}\n", day)
+}
+
+fn start_server() -> Server {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(AtomicUsize::new(0));
+
+    let counter = requests.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+
+            let mut authorized = false;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if header.to_ascii_lowercase().starts_with("cookie:")
+                    && header.contains(&format!("session={}", SESSION))
+                {
+                    authorized = true;
+                }
+            }
+
+            let path = request_line.split_whitespace().nth(1).unwrap_or("");
+            let day = path
+                .strip_prefix("/2021/day/")
+                .and_then(|rest| rest.strip_suffix("/input"));
+            let (status, body) = match day {
+                Some(_) if !authorized => ("400 Bad Request", "Puzzle inputs differ by user.\n".to_string()),
+                Some(day) => ("200 OK", input_for(day)),
+                None => ("404 Not Found", "Not found\n".to_string()),
+            };
+
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+        }
+    });
+
+    Server { base_url, requests }
+}
+
+fn cache_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-cache-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn downloads_and_reuses() {
+    let server = start_server();
+    let dir = cache_dir("reuse");
+    let cache = InputCache::new(&dir, &server.base_url).with_session(SESSION);
+
+    let input = cache.get(9).unwrap();
+    assert_eq!(input.text(), input_for("9"));
+    assert_eq!(fs::read_to_string(dir.join("day9.txt")).unwrap(), input_for("9"));
+    assert_eq!(server.requests.load(Ordering::SeqCst), 1);
+
+    //  The second time round the input comes straight from the cache
+    let input = cache.get(9).unwrap();
+    assert_eq!(input.text(), input_for("9"));
+    assert_eq!(server.requests.load(Ordering::SeqCst), 1);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn rejected_session() {
+    let server = start_server();
+    let dir = cache_dir("rejected");
+    let cache = InputCache::new(&dir, &server.base_url).with_session("wrong");
+
+    let err = cache.get(3).unwrap_err();
+    assert!(err.to_string().contains("400"), "{}", err);
+    assert!(!dir.join("day3.txt").exists());
+}
+
+#[test]
+fn missing_session() {
+    let server = start_server();
+    let cache = InputCache::new(cache_dir("missing"), &server.base_url);
+
+    let err = cache.get(3).unwrap_err();
+    assert!(err.to_string().contains("AOC_SESSION"), "{}", err);
+    assert_eq!(server.requests.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn builds_urls() {
+    let cache = InputCache::new("inputs", "http://localhost:8080/");
+    assert_eq!(cache.url(21), "http://localhost:8080/2021/day/21/input");
+    assert_eq!(cache.path(21), PathBuf::from("inputs").join("day21.txt"));
+}
@@ -0,0 +1,90 @@
+use std::env;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use common::Solution;
+
+const SAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+
+#[test]
+fn reads_stdin() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["--day", "2", "--input", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(SAMPLE.as_bytes()).unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Day 2 part 1: 150\nDay 2 part 2: 900\n"
+    );
+}
+
+#[test]
+fn runs_from_any_directory() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["--day", "1", "--part", "1"])
+        .current_dir(env::temp_dir())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8(output.stdout).unwrap().starts_with("Day 1 part 1: "));
+}
+
+#[test]
+fn parses_in_memory_input() {
+    let input = ch2::Day2::parse_str(SAMPLE).unwrap();
+    assert_eq!(ch2::Day2::part1(&input), Ok(Some(150.into())));
+
+    let err = ch2::Day2::parse_str("forward 5\nbackward 2\n").unwrap_err();
+    assert_eq!(err.to_string(), "<input>:2:1: unknown command 'backward'");
+}
+
+#[test]
+fn bench_skips_days_without_input() {
+    let cache = env::temp_dir().join(format!("aoc-bench-skip-{}", std::process::id()));
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["--bench", "--runs", "1"])
+        .env("AOC_CACHE_DIR", &cache)
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(output.status.success(), "{}", stderr);
+    for day in [5, 19] {
+        assert!(stderr.contains(&format!("Day {:>2} skipped, no input: ", day)), "{}", stderr);
+        assert!(!stdout.contains(&format!("Day {:>2} parse", day)));
+    }
+    assert!(stdout.contains("Day 22 part2"));
+}
+
+#[test]
+fn reports_parts_that_fail() {
+    use ch16::{LengthType, Packet};
+
+    //  Decodes fine, so part 1 is answered, but the value is too wide
+    let long = Packet::long_literal(5, &[9; 40]);
+    let packet = Packet::operator(2, 0, vec![Packet::literal(1, 1), long]);
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["--day", "16", "--input", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(packet.to_hex(LengthType::Count).as_bytes()).unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "Day 16 part 1: 8\n");
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "<stdin>:1:8: bit 29: value doesn't fit in 128 bits\n"
+    );
+}
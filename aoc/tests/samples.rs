@@ -0,0 +1,150 @@
+//  Runs every day against the sample inputs from the puzzle text, and
+//  checks the answers given there. `None` marks parts that are not
+//  implemented yet.
+
+use std::path::PathBuf;
+use aoc_input::Source;
+
+fn check(day: u8, file: &str, expected: [Option<&str>; 2]) {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "..", &format!("ch{}", day), file]
+        .iter()
+        .collect();
+    let input = Source::open(&path).unwrap();
+
+    let answers = aoc::solve_day(day, &input, &[1, 2])
+        .expect("no such day")
+        .unwrap_or_else(|err| panic!("{}", err));
+
+    for (part, (answer, expected)) in answers.iter().zip(expected).enumerate() {
+        let answer = match answer {
+            Ok(answer) => answer.as_ref().map(|answer| answer.to_string()),
+            Err(err) => panic!("{}", err),
+        };
+        assert_eq!(
+            answer.as_deref(),
+            expected,
+            "day {} part {} on {}",
+            day,
+            part + 1,
+            file
+        );
+    }
+}
+
+#[test]
+fn day1() {
+    check(1, "sample.txt", [Some("7"), Some("5")]);
+}
+
+#[test]
+fn day2() {
+    check(2, "sample.txt", [Some("150"), Some("900")]);
+}
+
+#[test]
+fn day3() {
+    check(3, "sample.txt", [Some("198"), Some("230")]);
+}
+
+#[test]
+fn day4() {
+    check(4, "sample.txt", [Some("4512"), Some("1924")]);
+}
+
+#[test]
+fn day5() {
+    check(5, "sample.txt", [Some("5"), Some("12")]);
+}
+
+#[test]
+fn day6() {
+    check(6, "sample.txt", [Some("5934"), Some("26984457539")]);
+}
+
+#[test]
+fn day7() {
+    check(7, "sample.txt", [Some("37"), Some("168")]);
+}
+
+#[test]
+fn day8() {
+    check(8, "sample.txt", [Some("26"), Some("61229")]);
+}
+
+#[test]
+fn day9() {
+    check(9, "sample.txt", [Some("15"), Some("1134")]);
+}
+
+#[test]
+fn day10() {
+    check(10, "sample.txt", [Some("26397"), Some("288957")]);
+}
+
+#[test]
+fn day11() {
+    check(11, "sample.txt", [Some("1656"), Some("195")]);
+    //  The smaller example from the puzzle text only lists the first
+    //  two steps, so these are the values it has always produced
+    check(11, "simple.txt", [Some("259"), Some("6")]);
+}
+
+#[test]
+fn day12() {
+    check(12, "sample.txt", [Some("10"), Some("36")]);
+    check(12, "sample2.txt", [Some("19"), Some("103")]);
+    check(12, "sample3.txt", [Some("226"), Some("3509")]);
+}
+
+#[test]
+fn day13() {
+    let square = "#####\n#...#\n#...#\n#...#\n#####\n.....\n.....\n";
+    check(13, "sample.txt", [Some("17"), Some(square)]);
+}
+
+#[test]
+fn day14() {
+    check(14, "sample.txt", [Some("1588"), Some("2188189693529")]);
+}
+
+#[test]
+fn day15() {
+    check(15, "sample.txt", [Some("40"), Some("315")]);
+}
+
+#[test]
+fn day16() {
+    check(16, "sample.txt", [Some("20"), Some("1")]);
+}
+
+#[test]
+fn day17() {
+    check(17, "sample.txt", [Some("45"), Some("112")]);
+}
+
+#[test]
+fn day18() {
+    //  sample.txt holds scratch numbers rather than a homework list
+    check(18, "sample2.txt", [Some("4140"), Some("3993")]);
+}
+
+#[test]
+fn day19() {
+    check(19, "sample.txt", [Some("79"), Some("3621")]);
+}
+
+#[test]
+fn day20() {
+    check(20, "sample.txt", [Some("35"), Some("3351")]);
+}
+
+#[test]
+fn day21() {
+    check(21, "sample.txt", [Some("739785"), Some("444356092776315")]);
+}
+
+#[test]
+fn day22() {
+    check(22, "sample.txt", [Some("39"), Some("39")]);
+    check(22, "sample2.txt", [Some("474140"), Some("2758514936282235")]);
+}
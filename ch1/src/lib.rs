@@ -0,0 +1,57 @@
+use aoc_input::Source;
+use common::{PartResult, Solution};
+
+fn count(input: &[i64]) -> u64 {
+    let mut count = 0;
+    let mut last: Option<i64> = None;
+    for depth in input {
+        if let Some(value) = last {
+            if *depth > value {
+                count += 1;
+            }
+        }
+        last = Some(*depth);
+    }
+
+    count
+}
+
+fn count_sliding(input: &[i64]) -> u64 {
+    let mut count = 0;
+    let mut last: Option<i64> = None;
+
+    let results = input.windows(3);
+    for i in results {
+        let sum = i[0] + i[1] + i[2];
+        if let Some(value) = last {
+            if sum > value {
+                count += 1;
+            }
+        }
+        last = Some(sum);
+    }
+
+    count
+}
+
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i64>;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        input.lines()
+            .iter()
+            .map(|line| line.parse())
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        Ok(Some(count(input).into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        Ok(Some(count_sliding(input).into()))
+    }
+}
@@ -0,0 +1,211 @@
+use std::collections::VecDeque;
+use aoc_input::{ErrorKind, Source};
+use common::{PartError, PartResult, Solution};
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+enum Scope {
+    Brace,
+    Square,
+    Squiggly,
+    Triangle
+}
+
+#[derive(Eq, PartialEq)]
+enum Token {
+    OpenBlock(Scope),
+    CloseBlock(Scope),
+}
+
+enum ParseError {
+    InvalidChar,
+    Unexpected(char),
+    Eof
+}
+
+
+struct Parser {
+    tokens: VecDeque<Token>,
+}
+
+impl Parser {
+    fn new() -> Parser {
+        Parser {
+            tokens: VecDeque::new()
+        }
+    }
+
+    fn autocomplete(&self) -> String {
+        let mut output: String = String::with_capacity(self.tokens.len());
+        for token in self.tokens.iter().rev() {
+            match token {
+                Token::OpenBlock(scope) => {
+                    let char = match scope {
+                        Scope::Brace => ')',
+                        Scope::Square => ']',
+                        Scope::Squiggly => '}',
+                        Scope::Triangle => '>',
+                    };
+                    output.push(char);
+                }
+                _ => panic!()
+            }
+        }
+
+        output
+    }
+
+    fn parse(&mut self, line: &str) -> Option<ParseError> {
+        for ch in line.chars() {
+            let scope = match ch {
+                '('|')' => Some(Scope::Brace),
+                '['|']' => Some(Scope::Square),
+                '{'|'}' => Some(Scope::Squiggly),
+                '<'|'>' => Some(Scope::Triangle),
+                _ => None,
+            };
+            let scope = match scope {
+                Some(scope) => scope,
+                None => return Some(ParseError::InvalidChar),
+            };
+
+
+            let token = match ch {
+                '('|'['|'{'|'<' => Some(Token::OpenBlock(scope)),
+                ')'|']'|'}'|'>' => Some(Token::CloseBlock(scope)),
+                _ => None
+            };
+            let token = match token {
+                Some(token) => token,
+                None => return Some(ParseError::InvalidChar),
+            };
+
+
+            match token {
+                Token::OpenBlock(_) => {
+                    self.tokens.push_back(token);
+                }
+
+                Token::CloseBlock(scope) => {
+                    let current = match self.tokens.pop_back() {
+                        Some(current) => current,
+                        None => return Some(ParseError::Unexpected(ch)),
+                    };
+
+                    if current != Token::OpenBlock(scope) {
+                        return Some(ParseError::Unexpected(ch));
+                    }
+                }
+            }
+        }
+
+        if !self.tokens.is_empty() {
+            return Some(ParseError::Eof);
+        }
+
+        None
+    }
+}
+
+fn autocomplete_score(str: &str) -> usize {
+    let mut score = 0;
+    for ch in str.chars() {
+        score *= 5;
+        score += match ch {
+            ')' => 1,
+            ']' => 2,
+            '}' => 3,
+            '>' => 4,
+            _ => 0
+        };
+    }
+
+    score
+}
+
+
+fn run_part1(input: &[String]) -> usize {
+    let mut score = 0;
+
+    for line in input.iter() {
+        let mut parser = Parser::new();
+
+        if let Some(ParseError::Unexpected(ch)) = parser.parse(line) {
+            match ch {
+                ')' => {
+                    score += 3
+                }
+                ']' => {
+                    score += 57
+                }
+                '}' => {
+                    score += 1197
+                }
+                '>' => {
+                    score += 25137
+                }
+                _ => {}
+            }
+        }
+    }
+
+    score
+}
+
+fn run_part2(input: &[String]) -> Result<usize, PartError> {
+    let mut autocompletion_scores: Vec<usize> = vec![];
+    let mut last_incomplete = 0;
+
+    for (idx, line) in input.iter().enumerate() {
+        let mut parser = Parser::new();
+
+        if let Some(ParseError::Eof) = parser.parse(line) {
+            let autocompleted = parser.autocomplete();
+            let score = autocomplete_score(&autocompleted);
+            autocompletion_scores.push(score);
+            last_incomplete = idx + 1;
+        }
+    }
+
+    //  Only an odd number of scores has a middle one. Point at the last
+    //  incomplete line, or past the end when there are none.
+    if autocompletion_scores.len().is_multiple_of(2) {
+        let line = if last_incomplete == 0 { input.len() + 1 } else { last_incomplete };
+        let message = format!("{} incomplete lines have no middle score", autocompletion_scores.len());
+        return Err(PartError::new(line, 0, ErrorKind::Invalid(message)));
+    }
+
+    autocompletion_scores.sort_unstable();
+    Ok(autocompletion_scores[autocompletion_scores.len() / 2])
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<String>;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        let lines = input.lines();
+        for line in lines.iter() {
+            for (idx, ch) in line.text().chars().enumerate() {
+                if !"()[]{}<>".contains(ch) {
+                    return Err(line.error_at(idx + 1, ErrorKind::InvalidChar(ch)));
+                }
+            }
+        }
+
+        Ok(lines
+            .iter()
+            .map(
+                |l| l.text().to_string()
+            )
+            .collect())
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        Ok(Some(run_part1(input).into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        Ok(Some(run_part2(input)?.into()))
+    }
+}
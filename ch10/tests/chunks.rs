@@ -0,0 +1,28 @@
+use aoc_input::ErrorKind;
+use ch10::Day10;
+use common::{PartError, Solution};
+
+#[test]
+fn parse_rejects_other_characters() {
+    let error = Day10::parse_str("()\n[<a>]\n").unwrap_err();
+    assert_eq!((error.line, error.column, error.kind), (2, 3, ErrorKind::InvalidChar('a')));
+
+    let error = Day10::parse_str("abc\n").unwrap_err();
+    assert_eq!(error.to_string(), "<input>:1:1: unexpected character 'a'");
+}
+
+#[test]
+fn part2_needs_a_middle_score() {
+    let input = Day10::parse_str("()\n[]\n").unwrap();
+    assert_eq!(Day10::part1(&input), Ok(Some(0.into())));
+    let kind = ErrorKind::Invalid("0 incomplete lines have no middle score".to_string());
+    assert_eq!(Day10::part2(&input), Err(PartError::new(3, 0, kind)));
+
+    let input = Day10::parse_str("(\n()\n[\n").unwrap();
+    let kind = ErrorKind::Invalid("2 incomplete lines have no middle score".to_string());
+    assert_eq!(Day10::part2(&input), Err(PartError::new(3, 0, kind)));
+
+    let input = Day10::parse_str("(\n()\n[\n<{\n(]\n").unwrap();
+    assert_eq!(Day10::part1(&input), Ok(Some(57.into())));
+    assert_eq!(Day10::part2(&input), Ok(Some(2.into())));
+}
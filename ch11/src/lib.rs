@@ -0,0 +1,114 @@
+use std::collections::VecDeque;
+use aoc_input::Source;
+use common::{Grid, PartResult, Solution};
+
+#[derive(Clone)]
+struct State {
+    energy: Grid<i64>,
+    flashed: Grid<bool>,
+    queue: VecDeque<(usize, usize)>,
+}
+
+impl State {
+    fn new(energy: &Grid<i64>) -> State {
+        State {
+            energy: energy.clone(),
+            flashed: Grid::filled(energy.width(), energy.height(), false),
+            queue: VecDeque::new(),
+        }
+    }
+
+    fn flash_at(&mut self, (x, y): (usize, usize)) {
+        self.flashed[(x, y)] = true;
+        for other in self.energy.neighbours8(x, y) {
+            self.energy[other] += 1;
+            if self.energy[other] > 9 && !self.flashed[other] && !self.queue.contains(&other) {
+                // println!("Adding {:?}", other);
+                self.queue.push_back(other);
+            }
+        }
+    }
+
+    fn clear_flashed(&mut self) {
+        for (energy, flashed) in self.energy.cells_mut().iter_mut().zip(self.flashed.cells_mut()) {
+            if *flashed {
+                *energy = 0;
+                *flashed = false;
+            }
+        }
+    }
+
+    fn step(&mut self) -> usize {
+        for energy in self.energy.cells_mut() {
+            *energy += 1;
+        }
+
+        for (point, &energy) in self.energy.iter() {
+            if energy > 9 {
+                // println!("Initial: adding {:?}", point);
+                self.queue.push_back(point);
+            }
+        }
+
+        let mut flash_count = 0;
+        while let Some(point) = self.queue.pop_front() {
+            // println!("Flashing {:?}", point);
+            if !self.flashed[point] {
+                flash_count += 1;
+                self.flash_at(point);
+            }
+        }
+        self.clear_flashed();
+
+        flash_count
+    }
+
+    #[allow(dead_code)]
+    fn pretty_print(&self) {
+        print!("{}", self.energy);
+    }
+}
+
+fn run(input: &Grid<i64>) -> usize {
+    let mut state = State::new(input);
+
+    let mut total = 0;
+    for _ in 0..100 {
+        let flashes = state.step();
+        total += flashes;
+    }
+
+    total
+}
+
+fn run_until_synchronized(input: &Grid<i64>) -> usize {
+    let mut state = State::new(input);
+
+    let mut step = 1;
+    loop {
+        let flashes = state.step();
+        if flashes == input.len() {
+            // state.pretty_print();
+            return step;
+        }
+        step += 1;
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<i64>;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        Grid::from_digits(input)
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        Ok(Some(run(input).into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        Ok(Some(run_until_synchronized(input).into()))
+    }
+}
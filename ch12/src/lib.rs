@@ -0,0 +1,310 @@
+use std::collections::HashMap;
+use std::fmt;
+use aoc_input::{ErrorKind, Source};
+use common::{PartResult, Solution};
+
+/// The most small caves a graph can have, as visited caves are kept
+/// in a bitmask
+pub const MAX_SMALL_CAVES: usize = 64;
+
+#[derive(Debug, Clone)]
+pub struct Cave {
+    pub name: String,
+    pub connections: Vec<usize>,
+    /// Bit in the visited mask, for small caves only
+    bit: Option<u32>,
+}
+
+impl Cave {
+    pub fn is_small(&self) -> bool {
+        self.name.starts_with(|ch: char| ch.is_ascii_lowercase())
+    }
+}
+
+/// A passage that can't be added to a `Graph`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectError {
+    /// Two big caves next to each other could be walked between forever
+    BigCaves { from: String, to: String },
+    /// The named cave would be one small cave more than `MAX_SMALL_CAVES`
+    TooManySmallCaves { name: String },
+}
+
+impl fmt::Display for ConnectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConnectError::BigCaves { from, to } => write!(f, "big caves '{}' and '{}' are connected", from, to),
+            ConnectError::TooManySmallCaves { .. } => write!(f, "more than {} small caves", MAX_SMALL_CAVES),
+        }
+    }
+}
+
+impl std::error::Error for ConnectError {}
+
+/// A cave system, where caves are referred to by their index
+#[derive(Debug, Clone)]
+pub struct Graph {
+    caves: Vec<Cave>,
+    names: HashMap<String, usize>,
+    small_count: u32,
+}
+
+type Memo = HashMap<(usize, u64, bool), usize>;
+
+impl Graph {
+    pub fn new() -> Graph {
+        Graph {
+            caves: vec![],
+            names: HashMap::new(),
+            small_count: 0,
+        }
+    }
+
+    pub fn caves(&self) -> &[Cave] {
+        &self.caves
+    }
+
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.names.get(name).copied()
+    }
+
+    fn cave_index(&mut self, name: &str) -> usize {
+        if let Some(idx) = self.find(name) {
+            return idx;
+        }
+
+        let mut cave = Cave {
+            name: name.to_string(),
+            connections: vec![],
+            bit: None,
+        };
+        if cave.is_small() {
+            cave.bit = Some(self.small_count);
+            self.small_count += 1;
+        }
+
+        self.caves.push(cave);
+        self.names.insert(name.to_string(), self.caves.len() - 1);
+        self.caves.len() - 1
+    }
+
+    /// Connects two caves. Passages are walked both ways, except that
+    /// nothing leads back into `start` or out of `end`. The graph is left
+    /// as it was if the passage can't be added.
+    pub fn connect(&mut self, from: &str, to: &str) -> Result<(), ConnectError> {
+        let is_big = |name: &str| name.starts_with(|ch: char| ch.is_ascii_uppercase());
+        if is_big(from) && is_big(to) {
+            return Err(ConnectError::BigCaves { from: from.to_string(), to: to.to_string() });
+        }
+
+        let names: &[&str] = if from == to { &[from] } else { &[from, to] };
+        let mut small_count = self.small_count as usize;
+        for &name in names {
+            if self.find(name).is_none() && name.starts_with(|ch: char| ch.is_ascii_lowercase()) {
+                small_count += 1;
+                if small_count > MAX_SMALL_CAVES {
+                    return Err(ConnectError::TooManySmallCaves { name: name.to_string() });
+                }
+            }
+        }
+
+        let from_idx = self.cave_index(from);
+        let to_idx = self.cave_index(to);
+
+        if from != "start" && to != "end" {
+            self.caves[to_idx].connections.push(from_idx);
+        }
+        if to != "start" && from != "end" {
+            self.caves[from_idx].connections.push(to_idx);
+        }
+        Ok(())
+    }
+
+    fn start_end(&self) -> Option<(usize, usize)> {
+        Some((self.find("start")?, self.find("end")?))
+    }
+
+    /// Counts the paths from `start` to `end` that visit small caves at
+    /// most once, except for a single small cave that may be visited
+    /// twice if `allow_revisit` is set.
+    pub fn count_paths(&self, allow_revisit: bool) -> usize {
+        let (start, end) = match self.start_end() {
+            Some(caves) => caves,
+            None => return 0,
+        };
+
+        //  How a cave was reached doesn't matter, only which small caves
+        //  are used up, so the count from each such state is only
+        //  worked out once
+        let mut memo: Memo = HashMap::new();
+        self.count_from(start, end, self.mark(0, start), !allow_revisit, &mut memo)
+    }
+
+    fn mark(&self, visited: u64, cave: usize) -> u64 {
+        match self.caves[cave].bit {
+            Some(bit) => visited | (1 << bit),
+            None => visited,
+        }
+    }
+
+    fn is_visited(&self, visited: u64, cave: usize) -> bool {
+        self.mark(visited, cave) == visited && self.caves[cave].bit.is_some()
+    }
+
+    fn count_from(&self, current: usize, end: usize, visited: u64, revisited: bool, memo: &mut Memo) -> usize {
+        if let Some(&count) = memo.get(&(current, visited, revisited)) {
+            return count;
+        }
+
+        let mut count = 0;
+        for &neighbour in &self.caves[current].connections {
+            if neighbour == end {
+                count += 1;
+            } else if !self.is_visited(visited, neighbour) {
+                count += self.count_from(neighbour, end, self.mark(visited, neighbour), revisited, memo);
+            } else if !revisited {
+                count += self.count_from(neighbour, end, visited, true, memo);
+            }
+        }
+
+        memo.insert((current, visited, revisited), count);
+        count
+    }
+
+    /// Lazily walks every path counted by `count_paths`, yielding the
+    /// names of the caves along it from `start` to `end`.
+    pub fn paths(&self, allow_revisit: bool) -> Paths<'_> {
+        let mut paths = Paths {
+            graph: self,
+            end: 0,
+            stack: vec![],
+            visited: 0,
+            revisited: !allow_revisit,
+        };
+
+        if let Some((start, end)) = self.start_end() {
+            paths.end = end;
+            paths.visited = self.mark(0, start);
+            paths.stack.push(Frame { cave: start, next: 0, revisit: false });
+        }
+        paths
+    }
+}
+
+impl Default for Graph {
+    fn default() -> Self {
+        Graph::new()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Frame {
+    cave: usize,
+    /// Index of the next connection to try
+    next: usize,
+    /// Whether entering this cave used up the one revisit
+    revisit: bool,
+}
+
+/// Iterator over the paths through a `Graph`, see `Graph::paths`
+#[derive(Debug, Clone)]
+pub struct Paths<'a> {
+    graph: &'a Graph,
+    end: usize,
+    stack: Vec<Frame>,
+    visited: u64,
+    revisited: bool,
+}
+
+impl<'a> Iterator for Paths<'a> {
+    type Item = Vec<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let graph = self.graph;
+        loop {
+            let frame = self.stack.last_mut()?;
+            let connections = &graph.caves[frame.cave].connections;
+
+            //  All ways on from here are done, so back out of this cave
+            if frame.next >= connections.len() {
+                let frame = self.stack.pop().unwrap();
+                if frame.revisit {
+                    self.revisited = false;
+                } else if let Some(bit) = graph.caves[frame.cave].bit {
+                    self.visited &= !(1 << bit);
+                }
+                continue;
+            }
+
+            let neighbour = connections[frame.next];
+            frame.next += 1;
+
+            if neighbour == self.end {
+                let path = self.stack
+                    .iter()
+                    .map(|frame| frame.cave)
+                    .chain([neighbour])
+                    .map(|cave| graph.caves[cave].name.as_str())
+                    .collect();
+                return Some(path);
+            }
+
+            if !graph.is_visited(self.visited, neighbour) {
+                self.visited = graph.mark(self.visited, neighbour);
+                self.stack.push(Frame { cave: neighbour, next: 0, revisit: false });
+            } else if !self.revisited {
+                self.revisited = true;
+                self.stack.push(Frame { cave: neighbour, next: 0, revisit: true });
+            }
+        }
+    }
+}
+
+fn load(input: &Source) -> aoc_input::Result<Graph> {
+    let mut graph = Graph::new();
+    for line in input.lines() {
+        let (from, to) = line.split_once("-")?;
+        for name in [from, to] {
+            if name.is_empty() || !name.chars().all(|ch| ch.is_ascii_alphabetic()) {
+                let message = format!("invalid cave name '{}'", name);
+                return Err(line.error(name, ErrorKind::Invalid(message)));
+            }
+        }
+
+        graph.connect(from, to).map_err(|err| {
+            let kind = ErrorKind::Invalid(err.to_string());
+            match err {
+                ConnectError::TooManySmallCaves { name } if name == to => line.error(to, kind),
+                ConnectError::TooManySmallCaves { .. } => line.error(from, kind),
+                ConnectError::BigCaves { .. } => line.error_at(0, kind),
+            }
+        })?;
+    }
+
+    for name in ["start", "end"] {
+        if graph.find(name).is_none() {
+            let message = format!("there is no '{}' cave", name);
+            return Err(aoc_input::Error::new(input.name(), 0, 0, ErrorKind::Invalid(message)));
+        }
+    }
+
+    Ok(graph)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Graph;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        load(input)
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        Ok(Some(input.count_paths(false).into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        Ok(Some(input.count_paths(true).into()))
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+use aoc_input::Source;
+use ch12::{ConnectError, Day12, Graph, MAX_SMALL_CAVES};
+use common::Solution;
+
+fn load(file: &str) -> Graph {
+    let path = format!("{}/{}", env!("CARGO_MANIFEST_DIR"), file);
+    Day12::parse(&Source::open(path).unwrap()).unwrap()
+}
+
+#[test]
+fn paths_match_counts() {
+    for file in ["sample.txt", "sample2.txt", "sample3.txt"] {
+        let graph = load(file);
+        for allow_revisit in [false, true] {
+            let paths: HashSet<Vec<&str>> = graph.paths(allow_revisit).collect();
+            assert_eq!(paths.len(), graph.count_paths(allow_revisit), "{}", file);
+        }
+    }
+}
+
+#[test]
+fn sample_paths() {
+    let graph = load("sample.txt");
+    let mut paths: Vec<String> = graph
+        .paths(false)
+        .map(|path| path.join(","))
+        .collect();
+    paths.sort();
+
+    let expected = [
+        "start,A,b,A,c,A,end",
+        "start,A,b,A,end",
+        "start,A,b,end",
+        "start,A,c,A,b,A,end",
+        "start,A,c,A,b,end",
+        "start,A,c,A,end",
+        "start,A,end",
+        "start,b,A,c,A,end",
+        "start,b,A,end",
+        "start,b,end",
+    ];
+    assert_eq!(paths, expected);
+}
+
+#[test]
+fn many_small_caves() {
+    //  A chain of 40 small caves, where each of the 39 steps along it
+    //  can be taken directly or through a big cave, gives 2^39 paths
+    let mut graph = Graph::new();
+    let names: Vec<String> = (0..40).map(|idx| format!("a{}", idx)).collect();
+    graph.connect("start", &names[0]).unwrap();
+    for (idx, pair) in names.windows(2).enumerate() {
+        let big = format!("B{}", idx);
+        graph.connect(&pair[0], &pair[1]).unwrap();
+        graph.connect(&pair[0], &big).unwrap();
+        graph.connect(&big, &pair[1]).unwrap();
+    }
+    graph.connect(&names[39], "end").unwrap();
+
+    assert_eq!(graph.count_paths(false), 1 << 39);
+    assert!(graph.count_paths(true) > 1 << 39);
+    assert_eq!(graph.paths(false).take(1000).count(), 1000);
+}
+
+#[test]
+fn big_caves_next_to_each_other() {
+    let mut graph = Graph::new();
+    let error = graph.connect("AB", "CD").unwrap_err();
+    assert_eq!(error, ConnectError::BigCaves { from: "AB".to_string(), to: "CD".to_string() });
+    assert!(graph.caves().is_empty());
+
+    let error = Day12::parse_str("start-A\nA-B\nA-end\n").unwrap_err();
+    assert_eq!(error.to_string(), "<input>:2: big caves 'A' and 'B' are connected");
+}
+
+#[test]
+fn too_many_small_caves() {
+    let mut graph = Graph::new();
+    for idx in 0..MAX_SMALL_CAVES - 2 {
+        graph.connect("A", &format!("c{}", idx)).unwrap();
+    }
+    graph.connect("end", "end").unwrap();
+
+    let error = graph.connect("d", "e").unwrap_err();
+    assert_eq!(error, ConnectError::TooManySmallCaves { name: "e".to_string() });
+    assert_eq!(graph.find("d"), None);
+}
@@ -1,22 +1,14 @@
-use std::borrow::Borrow;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::str::FromStr;
+use aoc_input::{ErrorKind, Source};
+use common::{PartResult, Point, Solution};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
-enum Axis {
+pub enum Axis {
     X,
     Y
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
-struct Point {
-    x: i64,
-    y: i64
-}
-
 #[derive(Debug, Copy, Clone)]
-struct Fold {
+pub struct Fold {
     axis: Axis,
     value: i64
 }
@@ -29,7 +21,7 @@ struct State {
 }
 
 impl State {
-    pub fn from_points(points: &Vec<Point>) -> State {
+    pub fn from_points(points: &[Point]) -> State {
         let width = points
             .iter()
             .max_by_key(|f| {
@@ -46,38 +38,40 @@ impl State {
             .y as usize + 1;
 
         State {
-            points: points.clone(),
-            width: width,
-            height: height
+            points: points.to_vec(),
+            width,
+            height
         }
     }
 
-    pub fn pretty_print(&self) {
+    pub fn render(&self) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
         for y in 0..self.height {
             for x in 0..self.width {
                 let point = Point { x: x as i64, y: y as i64 };
-                print!("{}", if !self.points.contains(point.borrow()) {
+                output.push(if !self.points.contains(&point) {
                     '.'
                 } else {
                     '#'
                 });
             }
-            println!();
+            output.push('\n');
         }
+        output
     }
 
     fn merge_duplicates(&mut self) {
         let mut vec: Vec<Point> = Vec::with_capacity(self.points.len());
         for point in self.points.iter() {
-            if !vec.contains(&point) {
-                vec.push(point.clone());
+            if !vec.contains(point) {
+                vec.push(*point);
             }
         }
         self.points = vec;
     }
 
     fn fold_horizontal(&mut self, coord: i64) {
-        for mut point in &mut self.points {
+        for point in &mut self.points {
             if point.x < coord {
                 continue;
             }
@@ -89,7 +83,7 @@ impl State {
     }
 
     fn fold_vertical(&mut self, coord: i64) {
-        for mut point in &mut self.points {
+        for point in &mut self.points {
             if point.y < coord {
                 continue;
             }
@@ -114,76 +108,84 @@ impl State {
 }
 
 
-fn load(filename: &str) -> (Vec<Point>, Vec<Fold>) {
-    let file = File::open(filename).expect("no such file");
-    let buf = BufReader::new(file);
-
-    let lines: Vec<String> = buf.lines()
-        .map(
-            |l| l.unwrap()
-        )
-        .collect();
-
+fn load(input: &Source) -> aoc_input::Result<(Vec<Point>, Vec<Fold>)> {
     let mut points: Vec<Point> = vec![];
     let mut folds: Vec<Fold> = vec![];
-    for line in lines {
-        if line.starts_with("fold") {
-            let command = &line[11..];
-            let parts: Vec<&str> = command.split('=').collect();
-            let axis = match parts[0].chars().nth(0).unwrap() {
-                'x' => Axis::X,
-                'y' => Axis::Y,
-                _ => panic!()
+    for line in input.lines() {
+        if line.text().starts_with("fold") {
+            let (command, value) = line.split_once("=")?;
+            let axis = match command.strip_prefix("fold along ") {
+                Some("x") => Axis::X,
+                Some("y") => Axis::Y,
+                _ => return Err(line.error_at(1, ErrorKind::Expected("fold along x|y".to_string())))
             };
-            let value = i64::from_str(parts[1]).unwrap();
+            let value: u32 = line.field(value)?;
 
             let fold = Fold {
                 axis,
-                value
+                value: value as i64
             };
             folds.push(fold);
 
             continue;
         }
 
-        if line.trim().is_empty() {
+        if line.text().trim().is_empty() {
             continue;
         }
 
-        let coords: Vec<i64> = line
-            .split(',')
-            .map(|f| {
-                i64::from_str(f).unwrap()
-            })
-            .collect();
+        let (x, y) = line.split_once(",")?;
+        let x: u32 = line.field(x)?;
+        let y: u32 = line.field(y)?;
 
         let point = Point {
-            x: coords[0],
-            y: coords[1]
+            x: x as i64,
+            y: y as i64
         };
 
         points.push(point);
     }
 
-    (points, folds)
+    if points.is_empty() || folds.is_empty() {
+        return Err(input.section().end_error());
+    }
+
+    Ok((points, folds))
 }
 
-fn run(input: &(Vec<Point>, Vec<Fold>)) {
-    let mut state = State::from_points(input.0.borrow());
-    println!("Width: {}", state.width);
-    println!("Height: {}", state.height);
+fn run_part1(input: &(Vec<Point>, Vec<Fold>)) -> usize {
+    let mut state = State::from_points(&input.0);
+
+    let fold = input.1[0];
+    state.fold(fold.axis, fold.value);
+    state.count_visible()
+}
+
+fn run_part2(input: &(Vec<Point>, Vec<Fold>)) -> String {
+    let mut state = State::from_points(&input.0);
     // println!("Initial state:");
-    // state.pretty_print();
+    // print!("{}", state.render());
 
     for fold in input.1.iter() {
         state.fold(fold.axis, fold.value);
-        println!("Visible dots: {}", state.count_visible());
     }
-    state.pretty_print();
+    state.render()
 }
 
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (Vec<Point>, Vec<Fold>);
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        load(input)
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        Ok(Some(run_part1(input).into()))
+    }
 
-fn main() {
-    let input = load("input.txt");
-    run(input.borrow());
+    fn part2(input: &Self::Input) -> PartResult {
+        Ok(Some(run_part2(input).into()))
+    }
 }
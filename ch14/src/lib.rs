@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+use aoc_input::{ErrorKind, Source};
+use common::{PartResult, Solution};
+
+#[derive(Debug)]
+struct Inserter {
+    patterns: Vec<(String, String)>,
+    counts: HashMap<String, usize>,
+    element_counts: HashMap<char, usize>
+}
+
+impl Inserter {
+    pub fn from_input(input: &(String, Vec<(String, String)>)) -> Inserter {
+        let chars: Vec<char> = input.0.chars().collect();
+        let mut counts: HashMap<String, usize> = HashMap::new();
+
+        for polymer in chars.windows(2) {
+            let str = String::from_iter(polymer);
+            *counts.entry(str).or_insert(0) += 1;
+        }
+
+        let mut element_counts: HashMap<char, usize> = HashMap::new();
+        for ch in chars {
+            *element_counts.entry(ch).or_insert(0) += 1;
+        }
+
+        Inserter {
+            patterns: input.1.clone(),
+            counts,
+            element_counts
+        }
+    }
+
+    fn find_pattern(&self, pair: &str) -> Option<&(String, String)> {
+        assert_eq!(pair.len(), 2);
+
+        self.patterns
+            .iter()
+            .find(|item| item.0 == pair)
+    }
+
+    /*
+    fn step(&mut self) {
+        let chars: Vec<char> = self.polymer.chars().collect();
+        let mut polymers: Vec<String> = chars
+            .windows(2)
+            .map(|f| String::from_iter(f.iter()))
+            .collect();
+        let mut new_polymer: String = String::with_capacity(self.polymer.len());
+
+        for (idx, pair) in &mut polymers.iter_mut().enumerate() {
+            let pattern = self.find_pattern(pair);
+            if pattern.is_none() {
+                new_polymer += pair;
+                continue;
+            }
+
+            let pattern = pattern.unwrap();
+            // println!("{}, {} matches pattern {},{}", idx, pair, pattern.0, pattern.1);
+
+            if idx == 0 {
+                new_polymer.write_char(pair.chars().nth(0).unwrap()).unwrap();
+            }
+            new_polymer.write_char(pattern.1.chars().nth(0).unwrap()).unwrap();
+            new_polymer.write_char(pair.chars().nth(1).unwrap()).unwrap();
+        }
+
+        self.polymer = new_polymer;
+    }
+     */
+
+    fn increment_char(&mut self, ch: char, n: usize) {
+        *self.element_counts.entry(ch).or_insert(0) += n;
+    }
+
+    fn step_optimized(&mut self) {
+        let current = self.counts.clone();
+        for (k, v) in current.iter() {
+            let pattern = match self.find_pattern(k.as_str()) {
+                Some(pattern) => pattern,
+                None => continue,
+            };
+            let inserted = pattern.1.chars().next().unwrap();
+
+            let ch: Vec<char> = k.chars().collect();
+            assert_eq!(ch.len(), 2);
+            let left = String::from_iter([ch[0], inserted]);
+            let right = String::from_iter([inserted, ch[1]]);
+
+            // dbg!(<String as Borrow<str>>::borrow(left.borrow()));
+            // dbg!(<String as Borrow<str>>::borrow(right.borrow()));
+
+            self.increment_char(inserted, *v);
+
+            let center_key = self.counts.get_mut(k.as_str()).unwrap();
+            *center_key -= v;
+            if *center_key == 0 {
+                self.counts.remove(k);
+            }
+            // dbg!(self.counts.borrow());
+
+            *self.counts.entry(left).or_insert(0) += *v;
+            // dbg!(self.counts.borrow());
+
+            *self.counts.entry(right).or_insert(0) += *v;
+            // dbg!(self.counts.borrow());
+
+            // let v = self.element_counts.get(pattern.1.chars().nth(0).unwrap());
+        }
+    }
+
+    fn score(&self) -> usize {
+        let most = self.element_counts.values().max().unwrap();
+        let least = self.element_counts.values().min().unwrap();
+        most - least
+    }
+
+    /*
+    pub fn count_occurences(&self) -> HashMap<char, usize> {
+        let mut map: HashMap<char, usize> = HashMap::new();
+
+        for char in self.polymer.chars() {
+            if map.contains_key(&char) {
+                let mut v = map.get_mut(&char).unwrap();
+                *v += 1;
+                continue;
+            }
+
+            map.insert(char, 1);
+        }
+
+        map
+    }
+     */
+}
+
+fn load(input: &Source) -> aoc_input::Result<(String, Vec<(String, String)>)> {
+    let sections = input.sections();
+    let start = match sections.first() {
+        Some(section) => section.line(0)?.text().trim().to_string(),
+        None => return Err(input.section().end_error()),
+    };
+    let mut patterns: Vec<(String, String)> = vec![];
+
+    for section in sections.iter().skip(1) {
+        for line in section.lines() {
+            let (pattern, replacement) = line.split_once("->")?;
+            let pattern = pattern.trim();
+            let replacement = replacement.trim();
+
+            if pattern.chars().count() != 2 {
+                let message = format!("expected a pair of elements, found '{}'", pattern);
+                return Err(line.error(pattern, ErrorKind::Invalid(message)));
+            }
+            if replacement.chars().count() != 1 {
+                let message = format!("expected a single element, found '{}'", replacement);
+                return Err(line.error(replacement, ErrorKind::Invalid(message)));
+            }
+
+            patterns.push((pattern.to_string(), replacement.to_string()));
+        }
+    }
+
+    Ok((start, patterns))
+}
+
+fn run(input: &(String, Vec<(String, String)>), steps: usize) -> usize {
+    let mut inserter = Inserter::from_input(input);
+
+    for _ in 0..steps {
+        inserter.step_optimized();
+    }
+    inserter.score()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = (String, Vec<(String, String)>);
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        load(input)
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        Ok(Some(run(input, 10).into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        Ok(Some(run(input, 40).into()))
+    }
+}
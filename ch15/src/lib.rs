@@ -0,0 +1,110 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use aoc_input::Source;
+use common::{Answer, Grid, PartResult, Solution};
+
+#[derive(Debug, Clone)]
+struct Map {
+    risk: Grid<i64>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Edge {
+    score: i64,
+    target: usize
+}
+
+impl Ord for Edge {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.score.cmp(&self.score)
+            .then_with(|| self.target.cmp(&other.target))
+    }
+}
+
+impl PartialOrd for Edge {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Map {
+    pub fn from_input(input: &Grid<i64>, scale: usize) -> Map {
+        let width = input.width();
+        let height = input.height();
+
+        let mut risk: Grid<i64> = Grid::filled(width * scale, height * scale, 0);
+        for ((x, y), &original) in input.iter() {
+            for m in 0..scale {
+                for n in 0..scale {
+                    let level = 1 + ((original - 1) as usize + m + n) % 9;
+                    risk[(n * width + x, m * height + y)] = level as i64;
+                }
+            }
+        }
+
+        Map { risk }
+    }
+
+    #[allow(dead_code)]
+    fn print(&self) {
+        print!("{}", self.risk);
+    }
+
+    fn run(&self) -> Option<i64> {
+        let mut shortest: Vec<i64> = vec![i64::MAX; self.risk.len()];
+        let mut queue: BinaryHeap<Edge> = BinaryHeap::new();
+
+        shortest[0] = 0;
+        queue.push(Edge{ score: 0, target: 0});
+
+        loop {
+            let current = queue.pop()?;
+
+            if current.target == self.risk.len()-1 {
+                return Some(current.score);
+            }
+
+            if current.score > shortest[current.target] {
+                continue;
+            }
+
+            let (x, y) = self.risk.position_of(current.target);
+            for other in self.risk.neighbours4(x, y) {
+                let edge = self.risk.index_of(other.0, other.1);
+                let cost_to_node = current.score + self.risk[other];
+
+                if cost_to_node < shortest[edge] {
+                    queue.push(Edge { score: cost_to_node, target: edge });
+                    shortest[edge] = cost_to_node;
+                }
+            }
+        }
+    }
+}
+
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Grid<i64>;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        //  Risk levels wrap around from 9 back to 1, so 0 is not a valid level
+        Grid::from_section(&input.section(), |ch| {
+            ch.to_digit(10)
+                .filter(|&digit| digit > 0)
+                .map(|digit| digit as i64)
+        })
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        let map = Map::from_input(input, 1);
+        Ok(map.run().map(Answer::from))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        let map = Map::from_input(input, 5);
+        // map.print();
+        Ok(map.run().map(Answer::from))
+    }
+}
@@ -0,0 +1,73 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacketErrorKind {
+    /// The transmission could not be read
+    Io(String),
+    /// A character in a hex transmission that is not a hex digit
+    BadHexDigit(char),
+    /// The transmission ended inside a packet's header or length field
+    TruncatedHeader,
+    /// The transmission ended inside a literal's value
+    TruncatedLiteral,
+    /// An operator's sub-packets are said to be longer than what is left
+    LengthOverrun { length: usize, available: usize },
+    /// An operator with a number of sub-packets it can't work with
+    BadArity { ptype: u8, found: usize },
+    /// Operators nested more deeply than the decoder allows
+    TooDeep { limit: usize },
+    /// A value too large for the integer type being evaluated in
+    Overflow { bits: u32 },
+    /// The bits padding a transmission out to a whole byte are not zeros
+    BadPadding,
+    /// A run of zeros between transmissions that is too long to be the
+    /// start of a packet
+    StrayZeros { bits: usize },
+}
+
+impl fmt::Display for PacketErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PacketErrorKind::Io(err) => write!(f, "{}", err),
+            PacketErrorKind::BadHexDigit(ch) => write!(f, "'{}' is not a hex digit", ch),
+            PacketErrorKind::TruncatedHeader => write!(f, "transmission ends inside a packet header"),
+            PacketErrorKind::TruncatedLiteral => write!(f, "transmission ends inside a literal value"),
+            PacketErrorKind::LengthOverrun { length, available } => write!(
+                f,
+                "sub-packets are {} bits long, but only {} bits are left",
+                length, available
+            ),
+            PacketErrorKind::BadArity { ptype, found } => {
+                write!(f, "operator type {} can't take {} sub-packets", ptype, found)
+            }
+            PacketErrorKind::TooDeep { limit } => write!(f, "operators nested more than {} deep", limit),
+            PacketErrorKind::Overflow { bits } => write!(f, "value doesn't fit in {} bits", bits),
+            PacketErrorKind::BadPadding => write!(f, "padding after a transmission is not all zeros"),
+            PacketErrorKind::StrayZeros { bits } => {
+                write!(f, "{} zero bits between transmissions don't start a packet", bits)
+            }
+        }
+    }
+}
+
+/// A transmission that could not be decoded or evaluated, pointing at
+/// the bit it went wrong at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PacketError {
+    pub offset: usize,
+    pub kind: PacketErrorKind,
+}
+
+impl PacketError {
+    pub fn new(offset: usize, kind: PacketErrorKind) -> PacketError {
+        PacketError { offset, kind }
+    }
+}
+
+impl fmt::Display for PacketError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "bit {}: {}", self.offset, self.kind)
+    }
+}
+
+impl std::error::Error for PacketError {}
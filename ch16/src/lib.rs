@@ -0,0 +1,489 @@
+mod error;
+mod render;
+mod stream;
+
+use bitvec::order::Msb0;
+use bitvec::slice::BitSlice;
+use bitvec::vec::BitVec;
+use aoc_input::{ErrorKind, Source};
+use common::{PartError, PartResult, Solution};
+
+pub use error::{PacketError, PacketErrorKind};
+pub use render::Render;
+pub use stream::{Format, PacketReader};
+
+/// The column of the hex digit holding the bit a packet went wrong at
+fn column_of(err: &PacketError) -> usize {
+    err.offset / 4 + 1
+}
+
+/// Reads the transmission on the first line. It is only decoded here,
+/// as part 1 has an answer even if the value is too large to work out.
+fn load(input: &Source) -> aoc_input::Result<Packet> {
+    let line = input.section().line(0)?;
+    decode_hex(line.text().trim_end()).map_err(|err| match err.kind {
+        PacketErrorKind::BadHexDigit(ch) => line.error_at(column_of(&err), ErrorKind::InvalidChar(ch)),
+        _ => line.error_at(column_of(&err), ErrorKind::Invalid(err.to_string())),
+    })
+}
+
+/// Where the parser gets its bits from, a whole transmission in memory
+/// or one being read in
+trait BitSource {
+    /// The next `width` bits, at most 64, or `None` if the input ends
+    /// before then
+    fn take(&mut self, width: usize) -> Result<Option<u64>, PacketError>;
+}
+
+impl BitSource for &BitSlice<u8, Msb0> {
+    fn take(&mut self, width: usize) -> Result<Option<u64>, PacketError> {
+        if width > self.len() {
+            return Ok(None);
+        }
+
+        let (bits, rest) = self.split_at(width);
+        *self = rest;
+        Ok(Some(bits.iter().fold(0, |value, bit| (value << 1) | *bit as u64)))
+    }
+}
+
+struct ParserState<'a> {
+    source: &'a mut dyn BitSource,
+    current: usize,
+    /// Where the packets being read have to end, which is the end of
+    /// the stream or of an operator's sub-packets
+    end: usize,
+    /// How many operators the packet being read is inside of
+    depth: usize
+}
+
+/// How deeply operators may be nested. Real transmissions stay far
+/// below this, and it keeps a crafted one from running the decoder and
+/// everything walking the packets after it out of stack.
+pub const MAX_DEPTH: usize = 256;
+
+impl ParserState<'_> {
+    /// Reads the next `width` bits, failing with `kind` at `offset` if
+    /// they run past the end
+    fn read(&mut self, width: usize, offset: usize, kind: PacketErrorKind) -> Result<u64, PacketError> {
+        if self.current + width > self.end {
+            return Err(PacketError::new(offset, kind));
+        }
+
+        match self.source.take(width)? {
+            Some(value) => {
+                self.current += width;
+                Ok(value)
+            }
+            None => Err(PacketError::new(offset, kind)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq)]
+pub struct PacketHeader {
+    version: u8,
+    ptype: u8,
+    /// Where the packet starts in the transmission it was decoded from,
+    /// 0 for packets built by hand
+    offset: usize
+}
+
+/// Packets are compared by what they hold, not where they were read from
+impl PartialEq for PacketHeader {
+    fn eq(&self, other: &Self) -> bool {
+        self.version == other.version && self.ptype == other.ptype
+    }
+}
+
+/// A literal of any length, kept as its hex digits with the most
+/// significant first and no leading zeros
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiteralPacket {
+    nibbles: Vec<u8>
+}
+
+impl LiteralPacket {
+    fn new(mut nibbles: Vec<u8>) -> LiteralPacket {
+        let leading = nibbles.iter().take_while(|&&nibble| nibble == 0).count();
+        nibbles.drain(..leading);
+        LiteralPacket { nibbles }
+    }
+
+    fn from_value(value: u128) -> LiteralPacket {
+        LiteralPacket::new((0..32).rev().map(|idx| ((value >> (idx * 4)) & 0b1111) as u8).collect())
+    }
+
+    fn from_bitstream(parser: &mut ParserState) -> Result<LiteralPacket, PacketError> {
+        let mut nibbles: Vec<u8> = vec![];
+        loop {
+            let group = parser.read(5, parser.current, PacketErrorKind::TruncatedLiteral)?;
+            nibbles.push((group & 0b1111) as u8);
+
+            //  Last group found
+            if (group & 0b10000) == 0 {
+                break;
+            }
+        }
+
+        Ok(LiteralPacket::new(nibbles))
+    }
+
+    /// The value if it fits in a `V`
+    fn value<V: Value>(&self) -> Option<V> {
+        if self.nibbles.len() * 4 > V::BITS as usize {
+            return None;
+        }
+
+        self.nibbles
+            .iter()
+            .try_fold(V::ZERO, |value, &nibble| value.checked_mul(V::from(16))?.checked_add(V::from(nibble)))
+    }
+}
+
+/// An integer type packets can be evaluated in, with checked arithmetic
+/// so overflow is reported rather than wrapping
+pub trait Value: Copy + Ord + From<u8> {
+    const BITS: u32;
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+impl Value for u64 {
+    const BITS: u32 = u64::BITS;
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        u64::checked_add(self, rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        u64::checked_mul(self, rhs)
+    }
+}
+
+impl Value for u128 {
+    const BITS: u32 = u128::BITS;
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        u128::checked_add(self, rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        u128::checked_mul(self, rhs)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperatorPacket {
+    packets: Vec<Packet>
+}
+
+impl OperatorPacket {
+    fn from_bitstream(parser: &mut ParserState, header: &PacketHeader) -> Result<OperatorPacket, PacketError> {
+        let truncated = PacketErrorKind::TruncatedHeader;
+        let length = parser.read(1, header.offset, truncated.clone())?;
+
+        let mut packets: Vec<Packet> = vec![];
+
+        if length == 0 {
+            let bit_length = parser.read(15, header.offset, truncated)? as usize;
+            let available = parser.end - parser.current;
+            if bit_length > available {
+                let kind = PacketErrorKind::LengthOverrun { length: bit_length, available };
+                return Err(PacketError::new(parser.current - 15, kind));
+            }
+
+            //  Sub-packets may not read past their length
+            let end = parser.end;
+            parser.end = parser.current + bit_length;
+            while parser.current < parser.end {
+                packets.push(Packet::from_bitstream(parser)?);
+            }
+            parser.end = end;
+        } else {
+            let packet_count = parser.read(11, header.offset, truncated)?;
+
+            for _ in 0..packet_count {
+                packets.push(Packet::from_bitstream(parser)?);
+            }
+        }
+
+        if !arity_allowed(header.ptype, packets.len()) {
+            let kind = PacketErrorKind::BadArity { ptype: header.ptype, found: packets.len() };
+            return Err(PacketError::new(header.offset, kind));
+        }
+
+        Ok(OperatorPacket{
+            packets
+        })
+    }
+}
+
+/// Comparisons take exactly two sub-packets, everything else at least one
+fn arity_allowed(ptype: u8, count: usize) -> bool {
+    match ptype {
+        5..=7 => count == 2,
+        _ => count >= 1,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Packet {
+    Literal (PacketHeader, LiteralPacket),
+    Operator (PacketHeader, OperatorPacket)
+}
+
+impl Packet {
+    /// Panics unless the version fits in 3 bits
+    pub fn literal(version: u8, value: u64) -> Packet {
+        assert!(version < 8, "versions are 3 bits");
+        Packet::wide_literal(version, value as u128)
+    }
+
+    /// Panics unless the version fits in 3 bits
+    pub fn wide_literal(version: u8, value: u128) -> Packet {
+        assert!(version < 8, "versions are 3 bits");
+        Packet::Literal(PacketHeader { version, ptype: 4, offset: 0 }, LiteralPacket::from_value(value))
+    }
+
+    /// A literal of any length, given as its hex digits with the most
+    /// significant first. Panics unless the version fits in 3 bits and
+    /// every digit in 4.
+    pub fn long_literal(version: u8, nibbles: &[u8]) -> Packet {
+        assert!(version < 8, "versions are 3 bits");
+        assert!(nibbles.iter().all(|&nibble| nibble < 16), "digits are 4 bits");
+        Packet::Literal(PacketHeader { version, ptype: 4, offset: 0 }, LiteralPacket::new(nibbles.to_vec()))
+    }
+
+    /// Panics unless the version and type fit in 3 bits, or for type 4
+    /// which is a literal
+    pub fn operator(version: u8, ptype: u8, packets: Vec<Packet>) -> Packet {
+        assert!(version < 8 && ptype < 8, "versions and types are 3 bits");
+        assert!(ptype != 4, "type 4 is for literals");
+        Packet::Operator(PacketHeader { version, ptype, offset: 0 }, OperatorPacket { packets })
+    }
+
+    /// The versions of this packet and all packets inside it added up
+    pub fn version_sum(&self) -> usize {
+        match self {
+            Packet::Literal(header, _) => header.version as usize,
+            Packet::Operator(header, op) => {
+                header.version as usize + op.packets.iter().map(Packet::version_sum).sum::<usize>()
+            }
+        }
+    }
+
+    /// Shows the packet as an expression, see `Render` for the options
+    pub fn render(&self) -> Render<'_> {
+        Render::new(self)
+    }
+
+    fn from_bitstream(parser: &mut ParserState) -> Result<Packet, PacketError> {
+        let offset = parser.current;
+        let version = parser.read(3, offset, PacketErrorKind::TruncatedHeader)? as u8;
+        let ptype = parser.read(3, offset, PacketErrorKind::TruncatedHeader)? as u8;
+        let header = PacketHeader { version, ptype, offset };
+        match ptype {
+            4 => {
+                let literal = LiteralPacket::from_bitstream(parser)?;
+                Ok(Packet::Literal(header, literal))
+            },
+
+            _ => {
+                if parser.depth == MAX_DEPTH {
+                    return Err(PacketError::new(offset, PacketErrorKind::TooDeep { limit: MAX_DEPTH }));
+                }
+
+                parser.depth += 1;
+                let op = OperatorPacket::from_bitstream(parser, &header)?;
+                parser.depth -= 1;
+                Ok(Packet::Operator(header, op))
+            }
+        }
+    }
+
+    /// Works out the value of the expression the packet holds. Packets
+    /// built by hand may have the wrong number of sub-packets, which
+    /// is reported at their offset of 0.
+    pub fn eval(&self) -> Result<u64, PacketError> {
+        self.eval_as::<u64>()
+    }
+
+    /// Evaluates in 128 bits, for expressions that overflow `eval`
+    pub fn eval_wide(&self) -> Result<u128, PacketError> {
+        self.eval_as::<u128>()
+    }
+
+    /// Evaluates in any `Value` type, failing if a literal or a sum or
+    /// product along the way doesn't fit in it
+    pub fn eval_as<V: Value>(&self) -> Result<V, PacketError> {
+        let overflow = |header: &PacketHeader| {
+            PacketError::new(header.offset, PacketErrorKind::Overflow { bits: V::BITS })
+        };
+        let (header, op) = match self {
+            Packet::Literal(header, literal) => return literal.value().ok_or_else(|| overflow(header)),
+            Packet::Operator(header, op) => (header, op),
+        };
+
+        if !arity_allowed(header.ptype, op.packets.len()) {
+            let kind = PacketErrorKind::BadArity { ptype: header.ptype, found: op.packets.len() };
+            return Err(PacketError::new(header.offset, kind));
+        }
+
+        let values = op.packets
+            .iter()
+            .map(Packet::eval_as)
+            .collect::<Result<Vec<V>, PacketError>>()?;
+
+        let flag = |set: bool| if set { V::ONE } else { V::ZERO };
+        let value = match header.ptype {
+            0 => values.iter().try_fold(V::ZERO, |sum, &value| sum.checked_add(value)),
+            1 => values.iter().try_fold(V::ONE, |product, &value| product.checked_mul(value)),
+            2 => values.iter().min().copied(),
+            3 => values.iter().max().copied(),
+            5 => Some(flag(values[0] > values[1])),
+            6 => Some(flag(values[0] < values[1])),
+            7 => Some(flag(values[0] == values[1])),
+            _ => unreachable!("type 4 is a literal"),
+        };
+        value.ok_or_else(|| overflow(header))
+    }
+}
+
+/// How an operator packet says where its sub-packets end
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LengthType {
+    /// Type 0, the total number of bits in the sub-packets
+    Bits,
+    /// Type 1, the number of sub-packets
+    Count,
+}
+
+pub type Bits = BitVec<u8, Msb0>;
+
+fn push_bits(bits: &mut Bits, value: u64, width: usize) {
+    for bit in (0..width).rev() {
+        bits.push((value >> bit) & 1 == 1);
+    }
+}
+
+impl Packet {
+    /// Writes the packet out as a transmission, with every operator
+    /// using `length_type`.
+    ///
+    /// Panics if sub-packets don't fit in the length field, which holds
+    /// 15 bits for `LengthType::Bits` and 11 for `LengthType::Count`.
+    pub fn encode(&self, length_type: LengthType) -> Bits {
+        let mut bits = Bits::new();
+        self.write(&mut bits, length_type);
+        bits
+    }
+
+    /// The transmission as hex, padded with zeros to a whole byte
+    pub fn to_hex(&self, length_type: LengthType) -> String {
+        let mut bits = self.encode(length_type);
+        bits.set_uninitialized(false);
+        bits.into_vec().iter().map(|byte| format!("{:02X}", byte)).collect()
+    }
+
+    fn write(&self, bits: &mut Bits, length_type: LengthType) {
+        let (header, packets) = match self {
+            Packet::Literal(header, literal) => {
+                push_bits(bits, header.version as u64, 3);
+                push_bits(bits, header.ptype as u64, 3);
+
+                //  At least one group, even for 0
+                let nibbles: &[u8] = if literal.nibbles.is_empty() { &[0] } else { &literal.nibbles };
+                for (idx, &nibble) in nibbles.iter().enumerate() {
+                    bits.push(idx + 1 < nibbles.len());
+                    push_bits(bits, nibble as u64, 4);
+                }
+                return;
+            }
+            Packet::Operator(header, op) => (header, &op.packets),
+        };
+
+        push_bits(bits, header.version as u64, 3);
+        push_bits(bits, header.ptype as u64, 3);
+        match length_type {
+            LengthType::Bits => {
+                let mut children = Bits::new();
+                for packet in packets {
+                    packet.write(&mut children, length_type);
+                }
+                assert!(children.len() < 1 << 15, "sub-packets are too long for a 15 bit length");
+
+                bits.push(false);
+                push_bits(bits, children.len() as u64, 15);
+                bits.extend_from_bitslice(&children);
+            }
+            LengthType::Count => {
+                assert!(packets.len() < 1 << 11, "too many sub-packets for an 11 bit count");
+
+                bits.push(true);
+                push_bits(bits, packets.len() as u64, 11);
+                for packet in packets {
+                    packet.write(bits, length_type);
+                }
+            }
+        }
+    }
+}
+
+/// Reads the packet at the start of a transmission
+pub fn decode(bits: &BitSlice<u8, Msb0>) -> Result<Packet, PacketError> {
+    let mut source = bits;
+    let mut state = ParserState {
+        source: &mut source,
+        current: 0,
+        end: bits.len(),
+        depth: 0
+    };
+
+    Packet::from_bitstream(&mut state)
+}
+
+/// Reads the packet at the start of a transmission written in hex
+pub fn decode_hex(text: &str) -> Result<Packet, PacketError> {
+    let mut reader = PacketReader::new(text.as_bytes(), Format::Hex);
+    let packet = match reader.next() {
+        Some(packet) => packet?,
+        None => return Err(PacketError::new(0, PacketErrorKind::TruncatedHeader)),
+    };
+    reader.skip_padding()?;
+    Ok(packet)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        load(input)
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        Ok(Some(input.version_sum().into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        //  Only go wide when the value doesn't fit in 64 bits. The
+        //  transmission is always on the first line.
+        let value = match input.eval() {
+            Ok(value) => value.into(),
+            Err(_) => input
+                .eval_wide()
+                .map_err(|err| PartError::new(1, column_of(&err), ErrorKind::Invalid(err.to_string())))?
+                .into(),
+        };
+        Ok(Some(value))
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt;
+use crate::Packet;
+
+fn operator_name(ptype: u8) -> &'static str {
+    match ptype {
+        0 => "sum",
+        1 => "product",
+        2 => "min",
+        3 => "max",
+        5 => "gt",
+        6 => "lt",
+        7 => "eq",
+        _ => unreachable!("type 4 is a literal"),
+    }
+}
+
+/// The decimal digits of a number given as hex digits, by long division
+fn decimal(nibbles: &[u8]) -> String {
+    let mut number: Vec<u8> = nibbles.to_vec();
+    let mut digits: Vec<u8> = vec![];
+    while !number.is_empty() {
+        let mut remainder = 0;
+        for nibble in number.iter_mut() {
+            let current = remainder * 16 + *nibble;
+            *nibble = current / 10;
+            remainder = current % 10;
+        }
+        digits.push(b'0' + remainder);
+
+        let leading = number.iter().take_while(|&&nibble| nibble == 0).count();
+        number.drain(..leading);
+    }
+
+    if digits.is_empty() {
+        return "0".to_string();
+    }
+    digits.iter().rev().map(|&digit| digit as char).collect()
+}
+
+/// Writes a packet out as an expression such as `sum(3, max(7, 8))`,
+/// optionally with each packet's version and bit offset as in
+/// `sum[v1 @0](...)`. The alternate form `{:#}` puts sub-packets on
+/// lines of their own, unless they are all literals.
+#[derive(Debug, Copy, Clone)]
+pub struct Render<'a> {
+    packet: &'a Packet,
+    versions: bool,
+    offsets: bool,
+}
+
+impl<'a> Render<'a> {
+    pub fn new(packet: &'a Packet) -> Render<'a> {
+        Render { packet, versions: false, offsets: false }
+    }
+
+    pub fn versions(mut self, show: bool) -> Render<'a> {
+        self.versions = show;
+        self
+    }
+
+    pub fn offsets(mut self, show: bool) -> Render<'a> {
+        self.offsets = show;
+        self
+    }
+
+    fn annotate(&self, f: &mut fmt::Formatter<'_>, packet: &Packet) -> fmt::Result {
+        let header = match packet {
+            Packet::Literal(header, _) | Packet::Operator(header, _) => header,
+        };
+
+        let mut notes: Vec<String> = vec![];
+        if self.versions {
+            notes.push(format!("v{}", header.version));
+        }
+        if self.offsets {
+            notes.push(format!("@{}", header.offset));
+        }
+
+        if notes.is_empty() {
+            return Ok(());
+        }
+        write!(f, "[{}]", notes.join(" "))
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, packet: &Packet, depth: usize) -> fmt::Result {
+        let (header, packets) = match packet {
+            Packet::Literal(_, literal) => {
+                write!(f, "{}", decimal(&literal.nibbles))?;
+                return self.annotate(f, packet);
+            }
+            Packet::Operator(header, op) => (header, &op.packets),
+        };
+
+        write!(f, "{}", operator_name(header.ptype))?;
+        self.annotate(f, packet)?;
+        write!(f, "(")?;
+
+        let nested = packets.iter().any(|child| matches!(child, Packet::Operator(..)));
+        if f.alternate() && nested {
+            let indent = "    ".repeat(depth + 1);
+            for (idx, child) in packets.iter().enumerate() {
+                let separator = if idx == 0 { "" } else { "," };
+                write!(f, "{}\n{}", separator, indent)?;
+                self.write(f, child, depth + 1)?;
+            }
+            write!(f, "\n{})", "    ".repeat(depth))
+        } else {
+            for (idx, child) in packets.iter().enumerate() {
+                if idx > 0 {
+                    write!(f, ", ")?;
+                }
+                self.write(f, child, depth)?;
+            }
+            write!(f, ")")
+        }
+    }
+}
+
+impl fmt::Display for Render<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, self.packet, 0)
+    }
+}
+
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&Render::new(self), f)
+    }
+}
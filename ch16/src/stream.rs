@@ -0,0 +1,197 @@
+use std::io::{BufReader, Bytes, Read};
+use crate::{BitSource, Packet, PacketError, PacketErrorKind, ParserState};
+
+/// How a transmission is written down
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    /// Hex digits in either case, with whitespace ignored
+    Hex,
+    /// The bits themselves, 8 to a byte
+    Raw,
+}
+
+/// Bits pulled from a reader as they are needed
+struct StreamSource<R: Read> {
+    bytes: Bytes<BufReader<R>>,
+    format: Format,
+    /// Bits read but not handed out yet, in the low `buffered` bits
+    buffer: u128,
+    buffered: usize,
+    /// Zero bits that come before the buffer. Runs of zeros are counted
+    /// rather than kept, as they may turn out to be padding.
+    zeros: usize,
+    /// How many bits have been pulled from the reader
+    pulled: usize,
+}
+
+impl<R: Read> StreamSource<R> {
+    /// Pulls the next byte or hex digit, returning its bits and how many
+    /// there are
+    fn pull(&mut self) -> Result<Option<(u128, usize)>, PacketError> {
+        loop {
+            let byte = match self.bytes.next() {
+                Some(Ok(byte)) => byte,
+                Some(Err(err)) => {
+                    return Err(PacketError::new(self.pulled, PacketErrorKind::Io(err.to_string())));
+                }
+                None => return Ok(None),
+            };
+
+            let unit = match self.format {
+                Format::Raw => (byte as u128, 8),
+                Format::Hex if byte.is_ascii_whitespace() => continue,
+                Format::Hex => match (byte as char).to_digit(16) {
+                    Some(nibble) => (nibble as u128, 4),
+                    None => {
+                        let kind = PacketErrorKind::BadHexDigit(byte as char);
+                        return Err(PacketError::new(self.pulled, kind));
+                    }
+                },
+            };
+            self.pulled += unit.1;
+            return Ok(Some(unit));
+        }
+    }
+
+    /// How many zeros come before the next one bit, among the bits read
+    /// so far
+    fn leading_zeros(&self) -> usize {
+        let significant = (u128::BITS - self.buffer.leading_zeros()) as usize;
+        self.zeros + self.buffered - significant
+    }
+
+    /// Whether everything left is zeros, which pad out the end of the
+    /// last transmission. Any zeros read along the way are still handed
+    /// out if more packets follow.
+    fn only_padding_left(&mut self) -> Result<bool, PacketError> {
+        if self.buffered > 0 {
+            if self.buffer != 0 {
+                return Ok(false);
+            }
+            self.zeros += self.buffered;
+            self.buffered = 0;
+        }
+
+        loop {
+            match self.pull()? {
+                None => return Ok(true),
+                Some((0, width)) => self.zeros += width,
+                Some((bits, width)) => {
+                    self.buffer = bits;
+                    self.buffered = width;
+                    return Ok(false);
+                }
+            }
+        }
+    }
+}
+
+impl<R: Read> BitSource for StreamSource<R> {
+    fn take(&mut self, width: usize) -> Result<Option<u64>, PacketError> {
+        let zeros = usize::min(self.zeros, width);
+        self.zeros -= zeros;
+        let width = width - zeros;
+
+        while self.buffered < width {
+            let (bits, size) = match self.pull()? {
+                Some(unit) => unit,
+                None => return Ok(None),
+            };
+            self.buffer = (self.buffer << size) | bits;
+            self.buffered += size;
+        }
+
+        //  The zeros are already in place at the top of the value
+        self.buffered -= width;
+        let value = (self.buffer >> self.buffered) as u64;
+        self.buffer &= (1 << self.buffered) - 1;
+        Ok(Some(value))
+    }
+}
+
+/// The most zeros a packet can start with: a version 0 sum whose
+/// sub-packets are given by their length, which is at least the 11 bits
+/// of a literal
+const MAX_LEADING_ZEROS: usize = 6 + 1 + 11;
+
+/// Decodes the top-level packets of a stream of transmissions one at a
+/// time, only holding on to the packet being read.
+///
+/// Each transmission is padded with zeros to a whole byte, and zeros
+/// after the last one are ignored. Anything else between transmissions
+/// is read as the start of the next one, and a run of zeros that can't
+/// be is rejected. Error offsets count bits from the
+/// start of the stream. Decoding stops at the first error, as there is
+/// no telling where the next packet would start.
+pub struct PacketReader<R: Read> {
+    source: StreamSource<R>,
+    offset: usize,
+    failed: bool,
+}
+
+impl<R: Read> PacketReader<R> {
+    pub fn new(reader: R, format: Format) -> PacketReader<R> {
+        PacketReader {
+            source: StreamSource {
+                bytes: BufReader::new(reader).bytes(),
+                format,
+                buffer: 0,
+                buffered: 0,
+                zeros: 0,
+                pulled: 0,
+            },
+            offset: 0,
+            failed: false,
+        }
+    }
+
+    /// Skips the zeros padding the last transmission out to a whole byte
+    pub(crate) fn skip_padding(&mut self) -> Result<(), PacketError> {
+        let padding = (8 - self.offset % 8) % 8;
+        if padding > 0 {
+            match self.source.take(padding)? {
+                Some(0) => self.offset += padding,
+                Some(_) => return Err(PacketError::new(self.offset, PacketErrorKind::BadPadding)),
+                //  A hex transmission may end on half a byte
+                None => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn read_packet(&mut self) -> Result<Option<Packet>, PacketError> {
+        self.skip_padding()?;
+        if self.source.only_padding_left()? {
+            return Ok(None);
+        }
+
+        let zeros = self.source.leading_zeros();
+        if zeros > MAX_LEADING_ZEROS {
+            return Err(PacketError::new(self.offset, PacketErrorKind::StrayZeros { bits: zeros }));
+        }
+
+        let mut state = ParserState {
+            source: &mut self.source,
+            current: self.offset,
+            end: usize::MAX,
+            depth: 0
+        };
+        let packet = Packet::from_bitstream(&mut state)?;
+        self.offset = state.current;
+        Ok(Some(packet))
+    }
+}
+
+impl<R: Read> Iterator for PacketReader<R> {
+    type Item = Result<Packet, PacketError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let packet = self.read_packet();
+        self.failed = packet.is_err();
+        packet.transpose()
+    }
+}
@@ -0,0 +1,103 @@
+use ch16::{decode, decode_hex, Day16, LengthType, Packet, PacketError, PacketErrorKind, MAX_DEPTH};
+use common::Solution;
+
+fn pair() -> Packet {
+    Packet::operator(1, 6, vec![Packet::literal(6, 10), Packet::literal(2, 20)])
+}
+
+#[test]
+fn bad_hex_digit() {
+    let error = decode_hex("D2FG28").unwrap_err();
+    assert_eq!(error, PacketError::new(12, PacketErrorKind::BadHexDigit('G')));
+}
+
+#[test]
+fn truncated() {
+    let error = decode_hex("D").unwrap_err();
+    assert_eq!(error, PacketError::new(0, PacketErrorKind::TruncatedHeader));
+
+    //  The header and the first group of 2021 are all there
+    let error = decode_hex("D2FE").unwrap_err();
+    assert_eq!(error, PacketError::new(16, PacketErrorKind::TruncatedLiteral));
+
+    //  Cut off in the middle of the 11 bit sub-packet count
+    let bits = pair().encode(LengthType::Count);
+    let error = decode(&bits[..12]).unwrap_err();
+    assert_eq!(error, PacketError::new(0, PacketErrorKind::TruncatedHeader));
+}
+
+#[test]
+fn length_overrun() {
+    let bits = pair().encode(LengthType::Bits);
+    let error = decode(&bits[..40]).unwrap_err();
+    let kind = PacketErrorKind::LengthOverrun { length: 27, available: 18 };
+    assert_eq!(error, PacketError::new(7, kind));
+}
+
+#[test]
+fn sub_packet_past_its_length() {
+    //  Shorten the length field from 27 to 20, which cuts the second
+    //  literal off after its header
+    let mut bits = pair().encode(LengthType::Bits);
+    for (idx, bit) in (7..22).enumerate() {
+        bits.set(bit, (20 >> (14 - idx)) & 1 == 1);
+    }
+
+    let error = decode(&bits).unwrap_err();
+    assert_eq!(error, PacketError::new(39, PacketErrorKind::TruncatedLiteral));
+}
+
+#[test]
+fn bad_arity() {
+    let inner = Packet::operator(0, 5, vec![Packet::literal(0, 1)]);
+    let error = decode(&inner.encode(LengthType::Bits)).unwrap_err();
+    assert_eq!(error, PacketError::new(0, PacketErrorKind::BadArity { ptype: 5, found: 1 }));
+
+    //  Behind the header and sub-packet count of its parent
+    let outer = Packet::operator(0, 0, vec![inner]);
+    let error = decode(&outer.encode(LengthType::Count)).unwrap_err();
+    assert_eq!(error.offset, 18);
+
+    let empty = Packet::operator(0, 2, vec![]);
+    let error = empty.eval().unwrap_err();
+    assert_eq!(error, PacketError::new(0, PacketErrorKind::BadArity { ptype: 2, found: 0 }));
+}
+
+#[test]
+fn eval() {
+    assert_eq!(pair().eval(), Ok(1));
+    assert_eq!(decode_hex("9C0141080250320F1802104A08").unwrap().eval(), Ok(1));
+}
+
+#[test]
+fn parse_errors() {
+    let error = Day16::parse_str("D2FE\n").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 5));
+    assert_eq!(error.to_string(), "<input>:1:5: bit 16: transmission ends inside a literal value");
+}
+
+#[test]
+fn parse_rejects_bad_arity() {
+    let bad = Packet::operator(0, 5, vec![Packet::literal(0, 1), Packet::literal(0, 2), Packet::literal(0, 3)]);
+    let packet = Packet::operator(0, 0, vec![Packet::literal(0, 1), bad]);
+
+    //  The comparison starts behind the sum's header and sub-packet count
+    //  and the literal 1, in the eighth hex digit
+    let error = Day16::parse_str(&packet.to_hex(LengthType::Count)).unwrap_err();
+    assert_eq!((error.line, error.column), (1, 8));
+    assert_eq!(error.to_string(), "<input>:1:8: bit 29: operator type 5 can't take 3 sub-packets");
+}
+
+#[test]
+fn nesting_limit() {
+    let nested = |depth: usize| {
+        (0..depth).fold(Packet::literal(0, 1), |packet, _| Packet::operator(0, 0, vec![packet]))
+    };
+
+    let packet = nested(MAX_DEPTH);
+    assert_eq!(decode(&packet.encode(LengthType::Count)), Ok(packet));
+
+    //  Each operator's header and sub-packet count takes 18 bits
+    let error = decode(&nested(MAX_DEPTH + 1).encode(LengthType::Count)).unwrap_err();
+    assert_eq!(error, PacketError::new(MAX_DEPTH * 18, PacketErrorKind::TooDeep { limit: MAX_DEPTH }));
+}
@@ -0,0 +1,47 @@
+use ch16::{decode, LengthType, Packet};
+
+fn tree() -> Packet {
+    Packet::operator(1, 0, vec![
+        Packet::literal(0, 3),
+        Packet::operator(7, 3, vec![Packet::literal(2, 7), Packet::literal(3, 8)]),
+        Packet::operator(5, 7, vec![Packet::literal(6, 0), Packet::literal(4, u64::MAX)]),
+    ])
+}
+
+#[test]
+fn puzzle_examples() {
+    assert_eq!(Packet::literal(6, 2021).to_hex(LengthType::Bits), "D2FE28");
+
+    let lt = Packet::operator(1, 6, vec![Packet::literal(6, 10), Packet::literal(2, 20)]);
+    assert_eq!(lt.to_hex(LengthType::Bits), "38006F45291200");
+
+    let children = vec![Packet::literal(2, 1), Packet::literal(4, 2), Packet::literal(1, 3)];
+    let max = Packet::operator(7, 3, children);
+    assert_eq!(max.to_hex(LengthType::Count), "EE00D40C823060");
+}
+
+#[test]
+fn round_trip() {
+    let packet = tree();
+    for length_type in [LengthType::Bits, LengthType::Count] {
+        assert_eq!(decode(&packet.encode(length_type)).unwrap(), packet);
+    }
+}
+
+#[test]
+fn length_types_differ() {
+    let packet = tree();
+    let bits = packet.encode(LengthType::Bits);
+    let count = packet.encode(LengthType::Count);
+
+    //  Each of the three operators trades a 15 bit length for an 11 bit count
+    assert_eq!(bits.len(), count.len() + 3 * 4);
+    assert!(!bits[6] && count[6]);
+}
+
+#[test]
+#[should_panic(expected = "too many sub-packets")]
+fn count_overflow() {
+    let packets = vec![Packet::literal(0, 1); 2048];
+    Packet::operator(0, 0, packets).encode(LengthType::Count);
+}
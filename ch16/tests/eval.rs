@@ -0,0 +1,103 @@
+use ch16::{decode, decode_hex, Day16, LengthType, Packet, PacketError, PacketErrorKind};
+use aoc_input::ErrorKind;
+use common::{Answer, PartError, Solution};
+
+const OVERFLOW_64: PacketErrorKind = PacketErrorKind::Overflow { bits: 64 };
+
+#[test]
+fn product_overflow() {
+    let big = Packet::literal(0, 1 << 40);
+    let product = Packet::operator(0, 0, vec![
+        Packet::literal(0, 1),
+        Packet::operator(0, 1, vec![big.clone(), big]),
+    ]);
+    let packet = decode(&product.encode(LengthType::Count)).unwrap();
+
+    //  The product, behind the sum's header and sub-packet count and
+    //  the literal 1
+    assert_eq!(packet.eval(), Err(PacketError::new(29, OVERFLOW_64)));
+    assert_eq!(packet.eval_wide(), Ok((1 << 80) + 1));
+}
+
+#[test]
+fn sum_overflow() {
+    let sum = Packet::operator(0, 0, vec![Packet::literal(0, u64::MAX), Packet::literal(0, 1)]);
+    assert_eq!(sum.eval(), Err(PacketError::new(0, OVERFLOW_64)));
+    assert_eq!(sum.eval_wide(), Ok(u64::MAX as u128 + 1));
+
+    let max = Packet::operator(0, 3, vec![Packet::literal(0, u64::MAX), Packet::literal(0, 1)]);
+    assert_eq!(max.eval(), Ok(u64::MAX));
+}
+
+#[test]
+fn wide_literals() {
+    let packet = Packet::wide_literal(3, 1 << 100);
+    let decoded = decode(&packet.encode(LengthType::Bits)).unwrap();
+    assert_eq!(decoded, packet);
+    assert_eq!(decoded.eval(), Err(PacketError::new(0, OVERFLOW_64)));
+    assert_eq!(decoded.eval_wide(), Ok(1 << 100));
+    assert_eq!(decoded.to_string(), (1u128 << 100).to_string());
+}
+
+#[test]
+fn long_literals() {
+    //  16^40, longer than any integer type
+    let mut nibbles = vec![1];
+    nibbles.extend([0; 40]);
+    let packet = Packet::long_literal(0, &nibbles);
+
+    let decoded = decode(&packet.encode(LengthType::Bits)).unwrap();
+    assert_eq!(decoded, packet);
+    assert_eq!(decoded.eval_wide(), Err(PacketError::new(0, PacketErrorKind::Overflow { bits: 128 })));
+    assert_eq!(decoded.to_string(), "1461501637330902918203684832716283019655932542976");
+
+    //  Still fine to compare once in range
+    let small = Packet::long_literal(0, &[0, 0, 0, 7]);
+    assert_eq!(small, Packet::literal(0, 7));
+    assert_eq!(Packet::long_literal(0, &[]).to_string(), "0");
+}
+
+#[test]
+fn leading_zero_groups() {
+    //  The literal 5 written as groups 0, 0, 5
+    let packet = decode_hex("D21028").unwrap();
+    assert_eq!(packet, Packet::literal(6, 5));
+    assert_eq!(packet.eval(), Ok(5));
+}
+
+#[test]
+fn part2_goes_wide() {
+    let packet = Packet::operator(0, 0, vec![Packet::wide_literal(0, 1 << 100), Packet::literal(0, 1)]);
+    let input = Day16::parse_str(&packet.to_hex(LengthType::Bits)).unwrap();
+    assert_eq!(Day16::part2(&input), Ok(Some(Answer::Number((1 << 100) + 1))));
+
+    let input = Day16::parse_str("C200B40A82").unwrap();
+    assert_eq!(Day16::part2(&input), Ok(Some(Answer::Number(3))));
+}
+
+#[test]
+fn part2_beyond_i128() {
+    let packet = Packet::wide_literal(0, u128::MAX);
+    let input = Day16::parse_str(&packet.to_hex(LengthType::Bits)).unwrap();
+    assert_eq!(Day16::part2(&input), Ok(Some(Answer::Text(u128::MAX.to_string()))));
+}
+
+#[test]
+fn part2_too_wide() {
+    //  A literal of 40 hex digits, behind the sum's header and sub-packet
+    //  count and the literal 1, so in the eighth hex digit
+    let long = Packet::long_literal(5, &[9; 40]);
+    let packet = Packet::operator(2, 0, vec![Packet::literal(1, 1), long]);
+    let input = Day16::parse_str(&packet.to_hex(LengthType::Count)).unwrap();
+
+    assert_eq!(Day16::part1(&input), Ok(Some(Answer::Number(8))));
+    let message = "bit 29: value doesn't fit in 128 bits".to_string();
+    assert_eq!(Day16::part2(&input), Err(PartError::new(1, 8, ErrorKind::Invalid(message))));
+}
+
+#[test]
+fn part2_on_packets_built_by_hand() {
+    let empty = Packet::operator(0, 3, vec![]);
+    let message = "bit 0: operator type 3 can't take 0 sub-packets".to_string();
+    assert_eq!(Day16::part2(&empty), Err(PartError::new(1, 1, ErrorKind::Invalid(message))));
+}
@@ -0,0 +1,41 @@
+use ch16::{decode_hex, Packet};
+
+fn expression() -> Packet {
+    Packet::operator(0, 0, vec![
+        Packet::literal(0, 3),
+        Packet::operator(0, 3, vec![Packet::literal(0, 7), Packet::literal(0, 8)]),
+        Packet::operator(0, 7, vec![Packet::literal(0, 1), Packet::literal(0, 1)]),
+    ])
+}
+
+#[test]
+fn display() {
+    assert_eq!(expression().to_string(), "sum(3, max(7, 8), eq(1, 1))");
+    assert_eq!(Packet::literal(6, 2021).to_string(), "2021");
+
+    let packet = decode_hex("9C0141080250320F1802104A08").unwrap();
+    assert_eq!(packet.to_string(), "eq(sum(1, 3), product(2, 2))");
+}
+
+#[test]
+fn annotations() {
+    let packet = decode_hex("38006F45291200").unwrap();
+    assert_eq!(packet.render().versions(true).to_string(), "lt[v1](10[v6], 20[v2])");
+    assert_eq!(packet.render().offsets(true).to_string(), "lt[@0](10[@22], 20[@33])");
+    assert_eq!(
+        packet.render().versions(true).offsets(true).to_string(),
+        "lt[v1 @0](10[v6 @22], 20[v2 @33])"
+    );
+}
+
+#[test]
+fn pretty() {
+    let expected = "\
+sum(
+    3,
+    max(7, 8),
+    eq(1, 1)
+)";
+    assert_eq!(format!("{:#}", expression()), expected);
+    assert_eq!(format!("{:#}", Packet::literal(0, 5)), "5");
+}
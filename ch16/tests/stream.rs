@@ -0,0 +1,145 @@
+use std::io::{self, Read};
+use ch16::{decode_hex, Day16, Format, LengthType, Packet, PacketError, PacketErrorKind, PacketReader};
+use common::Solution;
+
+fn bytes(packet: &Packet) -> Vec<u8> {
+    let mut bits = packet.encode(LengthType::Bits);
+    bits.set_uninitialized(false);
+    bits.into_vec()
+}
+
+/// Repeats a transmission without ever holding more than one copy
+struct Repeat {
+    transmission: Vec<u8>,
+    remaining: usize,
+    position: usize,
+}
+
+impl Read for Repeat {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut written = 0;
+        while written < buf.len() && self.remaining > 0 {
+            buf[written] = self.transmission[self.position];
+            written += 1;
+            self.position += 1;
+            if self.position == self.transmission.len() {
+                self.position = 0;
+                self.remaining -= 1;
+            }
+        }
+        Ok(written)
+    }
+}
+
+struct Broken;
+
+impl Read for Broken {
+    fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+        Err(io::Error::other("connection reset"))
+    }
+}
+
+#[test]
+fn concatenated_hex() {
+    let text = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt")).unwrap();
+    let expected: Vec<Packet> = text.lines().map(|line| decode_hex(line).unwrap()).collect();
+
+    let packets: Vec<Packet> = PacketReader::new(text.as_bytes(), Format::Hex)
+        .collect::<Result<Vec<Packet>, PacketError>>()
+        .unwrap();
+    assert_eq!(packets, expected);
+}
+
+#[test]
+fn raw_bytes() {
+    let first = Packet::operator(1, 6, vec![Packet::literal(6, 10), Packet::literal(2, 20)]);
+    let second = Packet::literal(6, 2021);
+
+    let mut stream = bytes(&first);
+    stream.extend(bytes(&second));
+    stream.extend([0, 0, 0]);
+
+    let packets: Vec<Packet> = PacketReader::new(stream.as_slice(), Format::Raw)
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(packets, [first, second]);
+}
+
+#[test]
+fn long_streams() {
+    let packet = Packet::operator(2, 0, vec![Packet::literal(1, 3), Packet::literal(0, 4)]);
+    let stream = Repeat { transmission: bytes(&packet), remaining: 100_000, position: 0 };
+
+    let mut count = 0;
+    for decoded in PacketReader::new(stream, Format::Raw) {
+        assert_eq!(decoded.unwrap().eval(), Ok(7));
+        count += 1;
+    }
+    assert_eq!(count, 100_000);
+}
+
+#[test]
+fn errors_stop_the_stream() {
+    //  The second transmission is cut off, at an offset counted from the
+    //  start of the stream
+    let mut reader = PacketReader::new("D2FE28 D2FE".as_bytes(), Format::Hex);
+    assert_eq!(reader.next(), Some(Ok(Packet::literal(6, 2021))));
+    assert_eq!(reader.next(), Some(Err(PacketError::new(40, PacketErrorKind::TruncatedLiteral))));
+    assert_eq!(reader.next(), None);
+
+    let mut reader = PacketReader::new("D2FE28XY".as_bytes(), Format::Hex);
+    assert_eq!(reader.next(), Some(Ok(Packet::literal(6, 2021))));
+    assert_eq!(reader.next(), Some(Err(PacketError::new(24, PacketErrorKind::BadHexDigit('X')))));
+
+    let mut reader = PacketReader::new(Broken, Format::Raw);
+    let error = reader.next().unwrap().unwrap_err();
+    assert_eq!(error.to_string(), "bit 0: connection reset");
+}
+
+#[test]
+fn odd_hex_digits() {
+    //  The literal 5, 11 bits, in three hex digits
+    assert_eq!(decode_hex("D0A"), Ok(Packet::literal(6, 5)));
+    assert_eq!(Day16::parse_str("D0A\n").unwrap(), Packet::literal(6, 5));
+
+    let error = Day16::parse_str("D2FG28\n").unwrap_err();
+    assert_eq!((error.column, error.to_string().ends_with("'G'")), (4, true));
+}
+
+#[test]
+fn empty_streams() {
+    assert_eq!(PacketReader::new("".as_bytes(), Format::Hex).count(), 0);
+    assert_eq!(PacketReader::new([0u8; 4].as_slice(), Format::Raw).count(), 0);
+}
+
+#[test]
+fn padding_must_be_zeros() {
+    //  The literal 2021 ends at bit 21, leaving 3 bits of padding
+    let mut reader = PacketReader::new("D2FE29 D2FE28".as_bytes(), Format::Hex);
+    assert_eq!(reader.next(), Some(Ok(Packet::literal(6, 2021))));
+    assert_eq!(reader.next(), Some(Err(PacketError::new(21, PacketErrorKind::BadPadding))));
+    assert_eq!(reader.next(), None);
+
+    let error = Day16::parse_str("D2FE2F\n").unwrap_err();
+    assert_eq!(error.to_string(), "<input>:1:6: bit 21: padding after a transmission is not all zeros");
+}
+
+#[test]
+fn zeros_between_transmissions() {
+    //  A single zero byte is read as the start of a sum, whose sub-packets
+    //  run off the end of the stream
+    let mut reader = PacketReader::new("D2FE28 00 D2FE28".as_bytes(), Format::Hex);
+    assert_eq!(reader.next(), Some(Ok(Packet::literal(6, 2021))));
+    let error = reader.next().unwrap().unwrap_err();
+    assert_eq!(error, PacketError::new(46, PacketErrorKind::TruncatedHeader));
+
+    //  Longer runs can't start any packet
+    let mut reader = PacketReader::new("D2FE28 000000 D2FE28".as_bytes(), Format::Hex);
+    assert_eq!(reader.next(), Some(Ok(Packet::literal(6, 2021))));
+    assert_eq!(reader.next(), Some(Err(PacketError::new(24, PacketErrorKind::StrayZeros { bits: 24 }))));
+    assert_eq!(reader.next(), None);
+
+    //  Trailing zeros are still fine
+    let reader = PacketReader::new("D2FE28 000000".as_bytes(), Format::Hex);
+    assert_eq!(reader.map(Result::unwrap).collect::<Vec<Packet>>(), [Packet::literal(6, 2021)]);
+}
@@ -0,0 +1,279 @@
+use aoc_input::{ErrorKind, Line, Source};
+use common::{PartResult, Solution};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Vec2 {
+    pub fn new(x: i64, y: i64) -> Vec2 {
+        Vec2 { x, y }
+    }
+}
+
+/// The target area, with `from` the corner closest to negative infinity
+/// on both axes
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Range {
+    pub from: Vec2,
+    pub to: Vec2,
+}
+
+/// Distance covered after `steps` steps when starting at `velocity`
+/// and slowing down by 1 every step, without ever stopping
+fn travelled(velocity: i64, steps: i64) -> i64 {
+    steps * velocity - steps * (steps - 1) / 2
+}
+
+/// The steps after which `travelled(velocity, steps) >= bound`. The
+/// path is a downward parabola, so these form a single interval which
+/// is found by solving the quadratic exactly.
+fn steps_reaching(velocity: i64, bound: i64) -> Option<(i64, i64)> {
+    let b = 2 * velocity + 1;
+    let discriminant = b * b - 8 * bound;
+    if discriminant < 0 {
+        return None;
+    }
+
+    let root = discriminant.isqrt();
+    let first = (b - root + 1).div_euclid(2);
+    let last = (b + root).div_euclid(2);
+    if first > last {
+        return None;
+    }
+
+    Some((first, last))
+}
+
+/// The steps after launch during which one coordinate is inside the
+/// target. `last` is `None` when it stays inside forever.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Window {
+    first: i64,
+    last: Option<i64>,
+}
+
+impl Window {
+    fn new(first: i64, last: Option<i64>) -> Option<Window> {
+        let first = i64::max(first, 1);
+        match last {
+            Some(last) if last < first => None,
+            _ => Some(Window { first, last }),
+        }
+    }
+}
+
+/// When x is within `from..=to`, for a velocity of 0 or more. Drag
+/// stops the probe once the velocity reaches 0, so x never decreases and
+/// the window may be open ended.
+fn x_window(velocity: i64, from: i64, to: i64) -> Option<Window> {
+    if velocity == 0 {
+        return if from <= 0 && to >= 0 { Window::new(1, None) } else { None };
+    }
+
+    let (first, _) = steps_reaching(velocity, from)?;
+    let last = steps_reaching(velocity, to + 1).map(|(overshoot, _)| overshoot - 1);
+    Window::new(first, last)
+}
+
+/// When y is within the target. A target below the launch point is only
+/// reached on the way down, one above it may be passed through on the
+/// way up as well.
+fn y_windows(velocity: i64, target: &Range) -> Vec<Window> {
+    let (first, last) = match steps_reaching(velocity, target.from.y) {
+        Some(steps) => steps,
+        None => return vec![],
+    };
+
+    let windows = match steps_reaching(velocity, target.to.y + 1) {
+        Some((above, below)) => [Window::new(first, Some(above - 1)), Window::new(below + 1, Some(last))],
+        None => [Window::new(first, Some(last)), None],
+    };
+    windows.into_iter().flatten().collect()
+}
+
+/// The x velocities heading one way that are ever inside the target,
+/// slowest first
+#[derive(Debug, Clone)]
+struct Side {
+    xs: Vec<(i64, Window)>,
+}
+
+impl Side {
+    /// Launching to the right, or to the left with `mirrored` set
+    fn new(target: &Range, mirrored: bool) -> Side {
+        let (from, to, sign) = if mirrored {
+            (-target.to.x, -target.from.x, -1)
+        } else {
+            (target.from.x, target.to.x, 1)
+        };
+
+        //  Straight up or down only needs checking once
+        let slowest = if mirrored { 1 } else { 0 };
+        let xs = (slowest..=to)
+            .filter_map(|x| Some((x * sign, x_window(x, from, to)?)))
+            .collect();
+        Side { xs }
+    }
+
+    /// The run of x velocities that are inside the target at the same
+    /// time as y is during `window`. Only y windows are passed in, and
+    /// those always end as the probe keeps falling.
+    fn matching(&self, window: &Window) -> std::ops::Range<usize> {
+        let last = window.last.unwrap();
+        let start = self.xs.partition_point(|(_, x)| x.first > last);
+        let end = self.xs.partition_point(|(_, x)| x.last.is_none_or(|x_last| x_last >= window.first));
+        start..end
+    }
+}
+
+/// Every launch velocity that puts the probe inside the target after
+/// some step.
+///
+/// Any faster and the probe skips past the target in a single step: x
+/// on the first one, and y on the first one past the launch point. The
+/// probe comes back down through y=0 one faster than it went up, so a
+/// target below can't be hit going up faster than it is deep.
+///
+/// The faster the x velocity, the sooner x enters the target and the
+/// sooner it leaves it again. So for each y velocity the x velocities
+/// that work in each direction are runs of the ones that reach the
+/// target at all, found by binary searching on both ends of its y
+/// windows.
+#[derive(Debug, Clone)]
+pub struct Launches {
+    sides: [Side; 2],
+    /// For each y velocity, slowest first, the x velocities that work
+    /// with it as ranges of indices into one of the `sides`
+    ys: Vec<(i64, usize, std::ops::Range<usize>)>,
+}
+
+impl Launches {
+    /// Panics if the target spans y=0, as there can then be endlessly
+    /// many ways to hit it
+    pub fn new(target: &Range) -> Launches {
+        assert!(target.from.y > 0 || target.to.y < 0, "target spans y=0");
+        let sides = [Side::new(target, false), Side::new(target, true)];
+
+        let velocities = if target.to.y < 0 {
+            target.from.y..=-target.from.y - 1
+        } else {
+            1..=target.to.y
+        };
+
+        let mut ys: Vec<(i64, usize, std::ops::Range<usize>)> = vec![];
+        for y in velocities {
+            let windows = y_windows(y, target);
+            for (idx, side) in sides.iter().enumerate() {
+                let mut runs: Vec<std::ops::Range<usize>> = windows
+                    .iter()
+                    .map(|window| side.matching(window))
+                    .filter(|run| !run.is_empty())
+                    .collect();
+
+                //  Going up and coming down may both work with the same
+                //  x velocity, which should only count once
+                runs.sort_by_key(|run| run.start);
+                let mut merged: Vec<std::ops::Range<usize>> = vec![];
+                for run in runs {
+                    match merged.last_mut() {
+                        Some(last) if run.start <= last.end => last.end = last.end.max(run.end),
+                        _ => merged.push(run),
+                    }
+                }
+
+                ys.extend(merged.into_iter().map(|run| (y, idx, run)));
+            }
+        }
+
+        Launches { sides, ys }
+    }
+
+    pub fn count(&self) -> usize {
+        self.ys.iter().map(|(_, _, xs)| xs.len()).sum()
+    }
+
+    /// The launch with the fastest y velocity, and among those the one
+    /// with the slowest x velocity
+    pub fn highest(&self) -> Option<Vec2> {
+        let y = self.ys.last()?.0;
+        self.ys
+            .iter()
+            .filter(|(launch_y, _, _)| *launch_y == y)
+            .map(|(_, side, xs)| self.sides[*side].xs[xs.start].0)
+            .min_by_key(|x| x.abs())
+            .map(|x| Vec2::new(x, y))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Vec2> + '_ {
+        self.ys.iter().flat_map(|(y, side, xs)| {
+            self.sides[*side].xs[xs.clone()]
+                .iter()
+                .map(move |(x, _)| Vec2::new(*x, *y))
+        })
+    }
+}
+
+/// The highest y position reached when launched at `velocity`
+pub fn highest_point(velocity: &Vec2) -> i64 {
+    if velocity.y > 0 {
+        travelled(velocity.y, velocity.y)
+    } else {
+        0
+    }
+}
+
+/// Parses one `name=from..to` part of the target line, in either order
+fn axis(line: &Line, text: &str, name: &str) -> aoc_input::Result<(i64, i64)> {
+    let text = text.trim();
+    let range = match text.strip_prefix(name).and_then(|rest| rest.strip_prefix('=')) {
+        Some(range) => range,
+        None => return Err(line.error(text, ErrorKind::Expected(format!("{}=", name)))),
+    };
+    let (from, to) = match range.split_once("..") {
+        Some(bounds) => bounds,
+        None => return Err(line.end_error(ErrorKind::Expected("..".to_string()))),
+    };
+
+    let (from, to): (i64, i64) = (line.field(from)?, line.field(to)?);
+    Ok((from.min(to), from.max(to)))
+}
+
+fn load(input: &Source) -> aoc_input::Result<Range> {
+    let line = input.section().line(0)?;
+    let (x_text, y_text) = match line.strip_prefix("target area: ")?.split_once(',') {
+        Some(parts) => parts,
+        None => return Err(line.end_error(ErrorKind::Expected(",".to_string()))),
+    };
+
+    let (x, y) = (axis(&line, x_text, "x")?, axis(&line, y_text, "y")?);
+    if y.0 <= 0 && y.1 >= 0 {
+        let message = "target area spans y=0, so it can be hit in endless ways".to_string();
+        return Err(line.error(y_text.trim(), ErrorKind::Invalid(message)));
+    }
+
+    Ok(Range {
+        from: Vec2::new(x.0, y.0),
+        to: Vec2::new(x.1, y.1),
+    })
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Range;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        load(input)
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        Ok(Launches::new(input).highest().map(|launch| highest_point(&launch).into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        Ok(Some(Launches::new(input).count().into()))
+    }
+}
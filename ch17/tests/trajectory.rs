@@ -0,0 +1,109 @@
+use ch17::{highest_point, Day17, Launches, Range, Vec2};
+use common::Solution;
+
+fn target(x: (i64, i64), y: (i64, i64)) -> Range {
+    Range { from: Vec2::new(x.0, y.0), to: Vec2::new(x.1, y.1) }
+}
+
+/// Steps the probe until it is inside the target or can't get there any more
+fn hits(velocity: Vec2, target: &Range) -> bool {
+    let (mut position, mut velocity) = (Vec2::new(0, 0), velocity);
+    while position.y >= target.from.y || velocity.y >= 0 {
+        position.x += velocity.x;
+        position.y += velocity.y;
+        velocity.x -= velocity.x.signum();
+        velocity.y -= 1;
+
+        if position.x >= target.from.x && position.x <= target.to.x
+            && position.y >= target.from.y && position.y <= target.to.y {
+            return true;
+        }
+    }
+    false
+}
+
+fn brute_force(target: &Range) -> Vec<Vec2> {
+    let mut launches: Vec<Vec2> = vec![];
+    for y in -200..200 {
+        for x in -200..200 {
+            if hits(Vec2::new(x, y), target) {
+                launches.push(Vec2::new(x, y));
+            }
+        }
+    }
+    launches
+}
+
+fn sorted(launches: impl Iterator<Item = Vec2>) -> Vec<(i64, i64)> {
+    let mut pairs: Vec<(i64, i64)> = launches.map(|v| (v.x, v.y)).collect();
+    pairs.sort_unstable();
+    pairs
+}
+
+#[test]
+fn sample() {
+    let target = target((20, 30), (-10, -5));
+    let launches = Launches::new(&target);
+    assert_eq!(launches.count(), 112);
+    assert_eq!(launches.highest(), Some(Vec2::new(6, 9)));
+    assert_eq!(launches.iter().map(|launch| highest_point(&launch)).max(), Some(45));
+}
+
+#[test]
+fn matches_simulation() {
+    let targets = [
+        target((20, 30), (-10, -5)),
+        target((1, 1), (-1, -1)),
+        target((5, 9), (-40, -1)),
+        target((60, 90), (-25, -20)),
+        target((3, 100), (-3, -3)),
+        target((-30, -20), (-10, -5)),
+        target((20, 30), (5, 10)),
+        target((-9, -5), (1, 40)),
+        target((-4, 6), (-12, -7)),
+        target((0, 0), (3, 3)),
+        target((-1, 1), (1, 1)),
+    ];
+
+    for target in targets {
+        assert_eq!(sorted(Launches::new(&target).iter()), sorted(brute_force(&target).into_iter()), "{:?}", target);
+    }
+}
+
+#[test]
+fn far_target() {
+    let target = target((200_000, 300_000), (-300_000, -250_000));
+    let launches = Launches::new(&target);
+    assert_eq!(launches.highest().map(|launch| launch.y), Some(299_999));
+    assert!(launches.count() > 0);
+}
+
+#[test]
+fn mirrored_targets() {
+    let right = Launches::new(&target((20, 30), (-10, -5)));
+    let left = Launches::new(&target((-30, -20), (-10, -5)));
+    let mirrored: Vec<Vec2> = right.iter().map(|v| Vec2::new(-v.x, v.y)).collect();
+    assert_eq!(sorted(left.iter()), sorted(mirrored.into_iter()));
+    assert_eq!(left.highest(), Some(Vec2::new(-6, 9)));
+}
+
+#[test]
+fn target_above() {
+    //  Straight up at 3 is at y=3 on the way up and again on the way
+    //  down, which only counts once
+    let launches = Launches::new(&target((0, 0), (3, 3)));
+    assert_eq!(launches.iter().collect::<Vec<Vec2>>(), [Vec2::new(0, 2), Vec2::new(0, 3)]);
+    assert_eq!(launches.count(), 2);
+}
+
+#[test]
+fn parse() {
+    let target = Day17::parse_str("target area: x=-30..-20, y=10..5\n").unwrap();
+    assert_eq!(target, Range { from: Vec2::new(-30, 5), to: Vec2::new(-20, 10) });
+
+    let error = Day17::parse_str("target area: x=20..30, y=-5..5\n").unwrap_err();
+    assert_eq!(error.column, 24);
+    assert!(Day17::parse_str("target area: x=20..30\n").is_err());
+    assert!(Day17::parse_str("target area: x=20..30, z=1..2\n").is_err());
+    assert!(Day17::parse_str("target area: x=20-30, y=1..2\n").is_err());
+}
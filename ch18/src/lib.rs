@@ -1,8 +1,6 @@
-use std::borrow::{Borrow, BorrowMut};
 use std::collections::VecDeque;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use crate::ExplodeState::{AddValues, Completed};
+use aoc_input::{ErrorKind, Source};
+use common::{Answer, PartResult, Solution};
 
 #[derive(Debug, Clone)]
 enum NumberType {
@@ -11,20 +9,13 @@ enum NumberType {
 }
 
 #[derive(Debug, Clone)]
-struct NumberPair {
+pub struct NumberPair {
     left: NumberType,
     right: NumberType,
 }
 
-#[derive(Debug, Clone)]
-enum ExplodeState {
-    None,
-    Completed,
-    AddValues(Option<i64>, Option<i64>),
-}
-
 impl NumberPair {
-    pub fn from_string(str: &String) -> Option<Box<NumberPair>> {
+    pub fn from_string(str: &str) -> Option<Box<NumberPair>> {
         let mut types: VecDeque<NumberType> = VecDeque::new();
 
         for ch in str.chars() {
@@ -50,28 +41,27 @@ impl NumberPair {
                     types.push_back(number);
                 }
                 _ => {
-                    eprintln!("Invalid token '{}'", ch);
                     return None;
                 }
             }
         }
 
-        assert_eq!(types.len(), 1);
-        let front = types.pop_front().unwrap();
+        if types.len() != 1 {
+            return None;
+        }
+        let front = types.pop_front()?;
 
-        return match front {
+        match front {
             NumberType::Number(bx) => Some(bx),
             _ => None
-        };
+        }
     }
 
-    pub fn add(lhs: &Box<NumberPair>, rhs: &Box<NumberPair>) -> Box<NumberPair> {
-        let mut number = Box::new(NumberPair {
-            left: NumberType::Number(lhs.clone()),
-            right: NumberType::Number(rhs.clone()),
-        });
-
-        number
+    pub fn add(lhs: &NumberPair, rhs: &NumberPair) -> Box<NumberPair> {
+        Box::new(NumberPair {
+            left: NumberType::Number(Box::new(lhs.clone())),
+            right: NumberType::Number(Box::new(rhs.clone())),
+        })
     }
 
     fn find_explodable_pair(&mut self, depth: usize, count: &mut usize) -> Option<(usize, (i64, i64))> {
@@ -79,19 +69,19 @@ impl NumberPair {
             NumberType::Number(branch) => {
                 if depth + 1 >= 4 {
                     let values = match (&branch.left, &branch.right) {
-                        (NumberType::Literal(left), NumberType::Literal(right)) => (left.clone(), right.clone()),
+                        (NumberType::Literal(left), NumberType::Literal(right)) => (*left, *right),
                         _ => panic!()
                     };
 
-                    return Some((count.clone(), values));
+                    return Some((*count, values));
                 }
 
-                let v = branch.find_explodable_pair(depth + 1, count.borrow_mut());
+                let v = branch.find_explodable_pair(depth + 1, count);
                 if v.is_some() {
                     return v;
                 }
             }
-            NumberType::Literal(literal) => {
+            NumberType::Literal(_) => {
                 *count += 1;
             }
         };
@@ -99,19 +89,19 @@ impl NumberPair {
             NumberType::Number(branch) => {
                 if depth + 1 >= 4 {
                     let values = match (&branch.left, &branch.right) {
-                        (NumberType::Literal(left), NumberType::Literal(right)) => (left.clone(), right.clone()),
+                        (NumberType::Literal(left), NumberType::Literal(right)) => (*left, *right),
                         _ => panic!()
                     };
 
-                    return Some((count.clone(), values));
+                    return Some((*count, values));
                 }
 
-                let v = branch.find_explodable_pair(depth + 1, count.borrow_mut());
+                let v = branch.find_explodable_pair(depth + 1, count);
                 if v.is_some() {
                     return v;
                 }
             }
-            NumberType::Literal(literal) => {
+            NumberType::Literal(_) => {
                 *count += 1;
             }
         };
@@ -119,10 +109,10 @@ impl NumberPair {
         None
     }
 
-    fn add_to_literal(&mut self, depth: usize, count: &mut usize, target: usize, value: i64) {
+    fn add_to_literal(&mut self, count: &mut usize, target: usize, value: i64) {
         match &mut self.left {
             NumberType::Number(branch) => {
-                branch.add_to_literal(depth + 1, count.borrow_mut(), target, value);
+                branch.add_to_literal(count, target, value);
             }
             NumberType::Literal(literal) => {
                 if *count == target {
@@ -133,7 +123,7 @@ impl NumberPair {
         };
         match &mut self.right {
             NumberType::Number(branch) => {
-                branch.add_to_literal(depth + 1, count.borrow_mut(), target, value);
+                branch.add_to_literal(count, target, value);
             }
             NumberType::Literal(literal) => {
                 if *count == target {
@@ -145,58 +135,51 @@ impl NumberPair {
     }
 
     fn explode_pair(&mut self, depth: usize) -> bool {
-        match &mut self.left {
-            NumberType::Number(branch) => {
-                if depth + 1 >= 4 {
-                    self.left = NumberType::Literal(0);
-                    return true;
-                }
+        if let NumberType::Number(branch) = &mut self.left {
+            if depth + 1 >= 4 {
+                self.left = NumberType::Literal(0);
+                return true;
+            }
 
-                let v = branch.explode_pair(depth + 1);
-                if v {
-                    return true;
-                }
+            let v = branch.explode_pair(depth + 1);
+            if v {
+                return true;
             }
-            _ => {}
         }
-        match &mut self.right {
-            NumberType::Number(branch) => {
-                if depth + 1 >= 4 {
-                    self.right = NumberType::Literal(0);
-                    return true;
-                }
+        if let NumberType::Number(branch) = &mut self.right {
+            if depth + 1 >= 4 {
+                self.right = NumberType::Literal(0);
+                return true;
+            }
 
-                let v = branch.explode_pair(depth + 1);
-                if v {
-                    return true;
-                }
+            let v = branch.explode_pair(depth + 1);
+            if v {
+                return true;
             }
-            _ => {}
         }
 
-        return false;
+        false
     }
 
     fn explode(&mut self) -> bool {
         let mut tmp = 0;
-        let explodable = self.find_explodable_pair(0, &mut tmp);
-        if explodable.is_none() {
-            return false;
-        }
+        let explodable = match self.find_explodable_pair(0, &mut tmp) {
+            Some(explodable) => explodable,
+            None => return false,
+        };
 
-        let explodable = explodable.unwrap();
         let target = explodable.0;
         let left = explodable.1.0;
         let right = explodable.1.1;
 
         tmp = 0;
         if target > 0 {
-            self.add_to_literal(0, &mut tmp, target - 1, left);
+            self.add_to_literal(&mut tmp, target - 1, left);
         }
         tmp = 0;
-        self.add_to_literal(0, &mut tmp, target + 2, right);
+        self.add_to_literal(&mut tmp, target + 2, right);
         self.explode_pair(0);
-        return true;
+        true
     }
 
     fn split(&mut self) -> bool {
@@ -232,7 +215,7 @@ impl NumberPair {
                 }
             }
         }
-        return false;
+        false
     }
 
     fn reduce(&mut self) {
@@ -251,68 +234,65 @@ impl NumberPair {
 
     fn magnitude(&self) -> i64 {
         let left = match &self.left {
-            NumberType::Literal(literal) => literal.clone(),
+            NumberType::Literal(literal) => *literal,
             NumberType::Number(branch) => branch.magnitude()
         };
         let right = match &self.right {
-            NumberType::Literal(literal) => literal.clone(),
+            NumberType::Literal(literal) => *literal,
             NumberType::Number(branch) => branch.magnitude()
         };
 
-        return 3 * left + 2 * right;
+        3 * left + 2 * right
     }
 }
 
 
-fn load(filename: &str) -> Vec<String> {
-    let file = File::open(filename).expect("no such file");
-    let buf = BufReader::new(file);
+pub struct Day18;
 
-    buf.lines()
-        .map(
-            |l| l.unwrap()
-        )
-        .collect()
-}
-
-fn main() {
-    let input = load("input.txt");
+impl Solution for Day18 {
+    type Input = Vec<NumberPair>;
 
-    let mut numbers: Vec<Box<NumberPair>> = vec![];
-    for line in input {
-        let number = NumberPair::from_string(line.borrow());
-        // dbg!(number.borrow());
-        if number.is_some() {
-            numbers.push(number.unwrap());
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        let mut numbers: Vec<NumberPair> = vec![];
+        for line in input.lines() {
+            match NumberPair::from_string(line.text().trim()) {
+                Some(number) => numbers.push(*number),
+                None => {
+                    let message = "invalid snailfish number".to_string();
+                    return Err(line.error_at(0, ErrorKind::Invalid(message)));
+                }
+            }
+        }
+        if numbers.is_empty() {
+            return Err(input.section().end_error());
         }
+
+        Ok(numbers)
     }
 
-    // let mut current = numbers[0].clone();
-    // for number in numbers.iter().skip(1) {
-    //     let mut sum = NumberPair::add(&current, number);
-    //     sum.reduce();
-    //     current = sum;
-    // }
-    // println!("Magnitude: {}", current.magnitude());
-
-    let mut max: Option<i64> = None;
-    for i in 0..numbers.len() {
-        for j in 0..numbers.len() {
-            let first = &numbers[i];
-            let second = &numbers[j];
-
-            let mut sum = NumberPair::add(first, second);
+    fn part1(input: &Self::Input) -> PartResult {
+        let mut current = input[0].clone();
+        for number in input.iter().skip(1) {
+            let mut sum = NumberPair::add(&current, number);
             sum.reduce();
+            current = *sum;
+        }
+        Ok(Some(current.magnitude().into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        let mut max: Option<i64> = None;
+        for first in input.iter() {
+            for second in input.iter() {
+                let mut sum = NumberPair::add(first, second);
+                sum.reduce();
 
-            let magnitude = sum.magnitude();
-            if max.is_none() {
-                max = Some(magnitude);
-            } else {
-                if magnitude > max.unwrap() {
+                let magnitude = sum.magnitude();
+                if max.is_none() || magnitude > max.unwrap() {
                     max = Some(magnitude);
                 }
             }
         }
+        Ok(max.map(Answer::from))
     }
-    println!("Largest magnitude: {}", max.unwrap());
 }
@@ -0,0 +1,306 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::{Add, Neg, Sub};
+use aoc_input::{ErrorKind, Line, Source};
+use common::{PartResult, Solution};
+
+/// How many beacons two scanners need to have in common before their
+/// reports are considered to overlap
+pub const MIN_SHARED: usize = 12;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Vec3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Vec3 {
+    pub fn new(x: i64, y: i64, z: i64) -> Vec3 {
+        Vec3 { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Vec3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    fn to_array(self) -> [i64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    fn from_array(values: [i64; 3]) -> Vec3 {
+        Vec3::new(values[0], values[1], values[2])
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Vec3;
+
+    fn add(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Vec3;
+
+    fn sub(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Vec3;
+
+    fn neg(self) -> Vec3 {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/// One of the 24 ways a scanner can be facing, as a rotation matrix
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rotation {
+    matrix: [[i64; 3]; 3],
+}
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation {
+        matrix: [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+    };
+
+    /// All 24 rotations: every signed permutation of the axes that
+    /// keeps the coordinate system right-handed
+    pub fn all() -> Vec<Rotation> {
+        let permutations = [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+
+        let mut rotations: Vec<Rotation> = Vec::with_capacity(24);
+        for permutation in permutations {
+            for signs in 0..8 {
+                let mut matrix = [[0i64; 3]; 3];
+                for (row, &column) in permutation.iter().enumerate() {
+                    matrix[row][column] = if signs & (1 << row) != 0 { -1 } else { 1 };
+                }
+
+                let rotation = Rotation { matrix };
+                if rotation.determinant() == 1 {
+                    rotations.push(rotation);
+                }
+            }
+        }
+
+        rotations
+    }
+
+    fn determinant(&self) -> i64 {
+        let m = &self.matrix;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    pub fn apply(&self, point: Vec3) -> Vec3 {
+        let point = point.to_array();
+        let mut output = [0i64; 3];
+        for (row, value) in output.iter_mut().enumerate() {
+            *value = (0..3).map(|column| self.matrix[row][column] * point[column]).sum();
+        }
+        Vec3::from_array(output)
+    }
+
+    /// The rotation that applies `other` first and then this one
+    pub fn compose(&self, other: &Rotation) -> Rotation {
+        let mut matrix = [[0i64; 3]; 3];
+        for (row, values) in matrix.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                *value = (0..3).map(|k| self.matrix[row][k] * other.matrix[k][column]).sum();
+            }
+        }
+        Rotation { matrix }
+    }
+
+    /// Rotations are orthogonal, so the inverse is the transpose
+    pub fn inverse(&self) -> Rotation {
+        let mut matrix = [[0i64; 3]; 3];
+        for (row, values) in matrix.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                *value = self.matrix[column][row];
+            }
+        }
+        Rotation { matrix }
+    }
+}
+
+/// A rotation followed by a translation, mapping points from one
+/// scanner's frame into another's
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Transform {
+    pub rotation: Rotation,
+    pub translation: Vec3,
+}
+
+impl Transform {
+    pub const IDENTITY: Transform = Transform {
+        rotation: Rotation::IDENTITY,
+        translation: Vec3 { x: 0, y: 0, z: 0 },
+    };
+
+    pub fn apply(&self, point: Vec3) -> Vec3 {
+        self.rotation.apply(point) + self.translation
+    }
+
+    /// The transform that applies `other` first and then this one
+    pub fn compose(&self, other: &Transform) -> Transform {
+        Transform {
+            rotation: self.rotation.compose(&other.rotation),
+            translation: self.apply(other.translation),
+        }
+    }
+
+    pub fn inverse(&self) -> Transform {
+        let rotation = self.rotation.inverse();
+        Transform {
+            rotation,
+            translation: -rotation.apply(self.translation),
+        }
+    }
+}
+
+/// Finds the transform mapping `other` onto `reference` under which at
+/// least `min_shared` beacons coincide.
+///
+/// For every rotation, each pair of beacons votes for the translation
+/// that would line them up; a translation with enough votes is the
+/// alignment.
+pub fn align(reference: &[Vec3], other: &[Vec3], min_shared: usize) -> Option<Transform> {
+    let mut votes: HashMap<Vec3, usize> = HashMap::new();
+    for rotation in Rotation::all() {
+        votes.clear();
+        for &point in other {
+            let rotated = rotation.apply(point);
+            for &target in reference {
+                let count = votes.entry(target - rotated).or_insert(0);
+                *count += 1;
+                if *count >= min_shared {
+                    return Some(Transform { rotation, translation: target - rotated });
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// All beacons and scanners, in the frame of the first scanner
+#[derive(Debug, Clone)]
+pub struct BeaconMap {
+    pub beacons: HashSet<Vec3>,
+    /// Maps each scanner's own frame into the frame of the first one
+    pub scanners: Vec<Transform>,
+}
+
+impl BeaconMap {
+    /// Aligns every scanner with one it overlaps with, starting from the
+    /// first. Returns the indices of the scanners that could not be
+    /// placed if there are any.
+    pub fn assemble(reports: &[Vec<Vec3>]) -> Result<BeaconMap, Vec<usize>> {
+        let mut transforms: Vec<Option<Transform>> = vec![None; reports.len()];
+        if reports.is_empty() {
+            return Ok(BeaconMap { beacons: HashSet::new(), scanners: vec![] });
+        }
+
+        transforms[0] = Some(Transform::IDENTITY);
+        let mut queue: VecDeque<usize> = VecDeque::from([0]);
+        while let Some(placed) = queue.pop_front() {
+            let to_global = transforms[placed].unwrap();
+            for other in 0..reports.len() {
+                if transforms[other].is_some() {
+                    continue;
+                }
+
+                if let Some(local) = align(&reports[placed], &reports[other], MIN_SHARED) {
+                    transforms[other] = Some(to_global.compose(&local));
+                    queue.push_back(other);
+                }
+            }
+        }
+
+        let missing: Vec<usize> = (0..reports.len())
+            .filter(|&idx| transforms[idx].is_none())
+            .collect();
+        if !missing.is_empty() {
+            return Err(missing);
+        }
+
+        let scanners: Vec<Transform> = transforms.into_iter().flatten().collect();
+        let beacons: HashSet<Vec3> = reports
+            .iter()
+            .zip(&scanners)
+            .flat_map(|(report, transform)| report.iter().map(|&point| transform.apply(point)))
+            .collect();
+
+        Ok(BeaconMap { beacons, scanners })
+    }
+
+    pub fn scanner_positions(&self) -> impl Iterator<Item = Vec3> + '_ {
+        self.scanners.iter().map(|transform| transform.translation)
+    }
+
+    pub fn max_scanner_distance(&self) -> i64 {
+        let positions: Vec<Vec3> = self.scanner_positions().collect();
+        positions
+            .iter()
+            .flat_map(|a| positions.iter().map(move |b| a.manhattan(b)))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Reads each scanner's report and lines them all up, pointing at the
+/// header of the first scanner that doesn't overlap with the others
+fn load(input: &Source) -> aoc_input::Result<BeaconMap> {
+    let mut reports: Vec<Vec<Vec3>> = vec![];
+    let mut headers: Vec<Line> = vec![];
+    for section in input.sections() {
+        let header = section.line(0)?;
+        let text = header.text().trim();
+        let is_header = text.starts_with("--- scanner ") && text.ends_with(" ---");
+        if !is_header {
+            return Err(header.error_at(1, ErrorKind::Expected("--- scanner N ---".to_string())));
+        }
+
+        let mut report: Vec<Vec3> = vec![];
+        for line in section.lines().iter().skip(1) {
+            let values: Vec<i64> = line.comma_list()?;
+            if values.len() != 3 {
+                let message = format!("expected 3 coordinates, found {}", values.len());
+                return Err(line.error_at(0, ErrorKind::Invalid(message)));
+            }
+            report.push(Vec3::new(values[0], values[1], values[2]));
+        }
+        reports.push(report);
+        headers.push(header);
+    }
+
+    BeaconMap::assemble(&reports).map_err(|missing| {
+        let scanners: Vec<String> = missing.iter().map(|idx| idx.to_string()).collect();
+        let message = format!("scanners {} don't overlap with the others", scanners.join(", "));
+        headers[missing[0]].error_at(1, ErrorKind::Invalid(message))
+    })
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = BeaconMap;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        load(input)
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        Ok(Some(input.beacons.len().into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        Ok(Some(input.max_scanner_distance().into()))
+    }
+}
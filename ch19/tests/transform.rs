@@ -0,0 +1,67 @@
+use std::collections::HashSet;
+use ch19::{align, Day19, Rotation, Transform, Vec3, MIN_SHARED};
+use common::Solution;
+
+fn points() -> Vec<Vec3> {
+    (0..15)
+        .map(|idx| Vec3::new(idx * 37 % 101 - 50, idx * idx % 89, 300 - idx * 53))
+        .collect()
+}
+
+#[test]
+fn rotations() {
+    let rotations = Rotation::all();
+    let distinct: HashSet<Rotation> = rotations.iter().copied().collect();
+    assert_eq!(distinct.len(), 24);
+
+    let point = Vec3::new(1, 2, 3);
+    for rotation in &rotations {
+        assert_eq!(rotation.inverse().apply(rotation.apply(point)), point);
+        assert_eq!(rotation.compose(&rotation.inverse()), Rotation::IDENTITY);
+    }
+}
+
+#[test]
+fn transforms_compose() {
+    let rotations = Rotation::all();
+    let first = Transform { rotation: rotations[5], translation: Vec3::new(10, -20, 30) };
+    let second = Transform { rotation: rotations[17], translation: Vec3::new(-4, 7, 1) };
+
+    let point = Vec3::new(3, -8, 12);
+    assert_eq!(second.compose(&first).apply(point), second.apply(first.apply(point)));
+    assert_eq!(first.inverse().apply(first.apply(point)), point);
+}
+
+#[test]
+fn aligns_rotated_reports() {
+    let reference = points();
+    for rotation in Rotation::all() {
+        let expected = Transform { rotation, translation: Vec3::new(68, -1246, -43) };
+        let other: Vec<Vec3> = reference
+            .iter()
+            .map(|&point| expected.inverse().apply(point))
+            .collect();
+
+        assert_eq!(align(&reference, &other, MIN_SHARED), Some(expected));
+    }
+
+    //  Too few shared beacons to be sure
+    assert_eq!(align(&reference[..11], &reference[..11], MIN_SHARED), None);
+}
+
+#[test]
+fn scanners_that_do_not_overlap() {
+    let text = "--- scanner 0 ---\n1,2,3\n\n--- scanner 1 ---\n4,5,6\n\n--- scanner 2 ---\n7,8,9\n";
+    let error = Day19::parse_str(text).unwrap_err();
+    assert_eq!(error.to_string(), "<input>:4:1: scanners 1, 2 don't overlap with the others");
+
+    let error = Day19::parse_str("--- scanner 0 ---\n1,2\n").unwrap_err();
+    assert_eq!(error.to_string(), "<input>:2: expected 3 coordinates, found 2");
+}
+
+#[test]
+fn one_scanner() {
+    let input = Day19::parse_str("--- scanner 0 ---\n1,2,3\n4,5,6\n").unwrap();
+    assert_eq!(Day19::part1(&input), Ok(Some(2.into())));
+    assert_eq!(Day19::part2(&input), Ok(Some(0.into())));
+}
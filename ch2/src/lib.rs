@@ -0,0 +1,89 @@
+use aoc_input::{ErrorKind, Source};
+use common::{PartResult, Solution};
+
+#[derive(Copy, Clone)]
+struct Position {
+    depth: i64,
+    horizontal: i64
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Direction {
+    Forward,
+    Up,
+    Down
+}
+
+fn run(input: &[(Direction, i64)], use_aim: bool) -> Position {
+    let mut horizontal = 0i64;
+    let mut depth = 0i64;
+    let mut aim = 0i64;
+
+    for cmd in input {
+        match cmd.0 {
+            Direction::Forward => {
+                horizontal += cmd.1;
+                if use_aim {
+                    depth += aim * cmd.1;
+                }
+            }
+
+            Direction::Up => {
+                if use_aim {
+                    aim -= cmd.1;
+                } else {
+                    depth -= cmd.1;
+                }
+            }
+
+            Direction::Down => {
+                if use_aim {
+                    aim += cmd.1;
+                } else {
+                    depth += cmd.1;
+                }
+            }
+        }
+    }
+
+    Position {
+        depth,
+        horizontal
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(Direction, i64)>;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        input.lines()
+            .iter()
+            .map(|line| {
+                let (command, amount) = line.split_once(" ")?;
+                let direction = match command {
+                    "forward" => Direction::Forward,
+                    "up" => Direction::Up,
+                    "down" => Direction::Down,
+                    _ => {
+                        let message = format!("unknown command '{}'", command);
+                        return Err(line.error(command, ErrorKind::Invalid(message)));
+                    }
+                };
+
+                Ok((direction, line.field(amount)?))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        let output = run(input, false);
+        Ok(Some((output.horizontal * output.depth).into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        let output = run(input, true);
+        Ok(Some((output.horizontal * output.depth).into()))
+    }
+}
@@ -0,0 +1,108 @@
+use aoc_input::{ErrorKind, Source};
+use common::{Grid, PartResult, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    algorithm: Vec<bool>,
+    image: Grid<bool>,
+    outside_state: bool
+}
+
+impl Input {
+
+    fn get_surround(&self, x: i64, y: i64) -> u64 {
+        let mut output: u64 = 0;
+        for yoff in -1..=1 {
+            for xoff in -1..=1 {
+                output <<= 1;
+                let state = self.image
+                    .get_signed(x + xoff, y + yoff)
+                    .copied()
+                    .unwrap_or(self.outside_state);
+                output |= state as u64;
+            }
+        }
+
+        output
+    }
+
+    fn step(&mut self) {
+        let mut output: Grid<bool> = Grid::filled(self.image.width() + 2, self.image.height() + 2, false);
+
+        for (x, y) in output.positions() {
+            let actual_x = (x as i64) - 1;
+            let actual_y = (y as i64) - 1;
+            let score = self.get_surround(actual_x, actual_y);
+            output[(x, y)] = self.algorithm[score as usize];
+        }
+        self.outside_state = if self.outside_state {
+            self.algorithm[511]
+        } else {
+            self.algorithm[0]
+        };
+        self.image = output;
+    }
+
+    #[allow(dead_code)]
+    fn pretty_print(&self) {
+        print!("{}", self.image);
+    }
+
+    fn count_lit(&self) -> usize {
+        self.image
+            .cells()
+            .iter()
+            .filter(|&v| *v)
+            .count()
+    }
+
+}
+
+fn load(input: &Source) -> aoc_input::Result<Input> {
+    let sections = input.sections();
+    if sections.len() != 2 {
+        let message = "expected an algorithm and an image separated by a blank line";
+        return Err(aoc_input::Error::new(input.name(), 0, 0, ErrorKind::Invalid(message.to_string())));
+    }
+
+    let algorithm = Grid::from_map(&sections[0])?;
+    if algorithm.height() != 1 || algorithm.width() != 512 {
+        let line = sections[0].line(0)?;
+        let message = "the algorithm must be a single line of 512 pixels";
+        return Err(line.error_at(0, ErrorKind::Invalid(message.to_string())));
+    }
+
+    Ok(Input {
+        algorithm: algorithm.cells().to_vec(),
+        image: Grid::from_map(&sections[1])?,
+        outside_state: false
+    })
+}
+
+fn run(input: &Input, steps: usize) -> usize {
+    let mut input = input.clone();
+
+    for _ in 0..steps {
+        input.step();
+        // input.pretty_print();
+    }
+    input.count_lit()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Input;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        load(input)
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        Ok(Some(run(input, 2).into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        Ok(Some(run(input, 50).into()))
+    }
+}
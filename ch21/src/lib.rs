@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use aoc_input::{ErrorKind, Source};
+use common::{PartResult, Solution};
+
+/// Score a player needs to win with the Dirac die
+const DIRAC_TARGET: usize = 21;
+
+/// How many of the 27 ways to roll the Dirac die three times add up to
+/// each total
+fn roll_frequencies() -> [(usize, u64); 7] {
+    let mut frequencies = [(0, 0); 7];
+    for (idx, frequency) in frequencies.iter_mut().enumerate() {
+        frequency.0 = idx + 3;
+    }
+    for a in 1..=3 {
+        for b in 1..=3 {
+            for c in 1..=3 {
+                frequencies[a + b + c - 3].1 += 1;
+            }
+        }
+    }
+    frequencies
+}
+
+type Universe = ([usize; 2], [usize; 2], usize);
+
+#[derive(Debug, Clone)]
+struct Player {
+    position: usize,
+    score: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    players: [Player; 2],
+    current_roll: usize,
+    roll_count: usize,
+}
+
+impl Game {
+    fn new(first: usize, second: usize) -> Game {
+        Game {
+            players: [
+                Player { position: first - 1, score: 0 },
+                Player { position: second - 1, score: 0 }
+            ],
+            current_roll: 0,
+            roll_count: 0,
+        }
+    }
+
+    fn next_roll(&mut self) -> usize {
+        let value = self.current_roll;
+        self.current_roll = (self.current_roll + 1) % 100;
+        self.roll_count += 1;
+        value + 1
+    }
+
+    fn run(&mut self) -> usize {
+        loop {
+            for i in 0..2 {
+                let result = self.next_roll() + self.next_roll() + self.next_roll();
+
+                let player = &mut self.players[i];
+                player.position = (player.position + result) % 10;
+                player.score += (player.position) + 1;
+                if player.score >= 1000 {
+                    let losing_score = self.players[if i == 0 { 1 } else { 0 }].score;
+                    return losing_score * self.roll_count;
+                }
+            }
+        }
+    }
+
+    /// Counts the universes in which each player wins with the Dirac
+    /// die. Universes that reach the same positions, scores and turn
+    /// play out the same way, so each of those is only counted once.
+    pub fn count_wins(&self) -> [u64; 2] {
+        let positions = [self.players[0].position, self.players[1].position];
+        let scores = [self.players[0].score, self.players[1].score];
+
+        let mut memo: HashMap<Universe, [u64; 2]> = HashMap::new();
+        count_universes((positions, scores, 0), &roll_frequencies(), &mut memo)
+    }
+}
+
+fn count_universes(universe: Universe, rolls: &[(usize, u64); 7], memo: &mut HashMap<Universe, [u64; 2]>) -> [u64; 2] {
+    if let Some(&wins) = memo.get(&universe) {
+        return wins;
+    }
+
+    let (positions, scores, turn) = universe;
+    let mut wins = [0u64; 2];
+    for &(total, count) in rolls {
+        let mut positions = positions;
+        let mut scores = scores;
+        positions[turn] = (positions[turn] + total) % 10;
+        scores[turn] += positions[turn] + 1;
+
+        if scores[turn] >= DIRAC_TARGET {
+            wins[turn] += count;
+        } else {
+            let next = count_universes((positions, scores, 1 - turn), rolls, memo);
+            wins[0] += next[0] * count;
+            wins[1] += next[1] * count;
+        }
+    }
+
+    memo.insert(universe, wins);
+    wins
+}
+
+fn load(input: &Source) -> aoc_input::Result<Game> {
+    let section = input.section();
+    let mut positions = [0usize; 2];
+    for (idx, position) in positions.iter_mut().enumerate() {
+        let line = section.line(idx)?;
+        let prefix = format!("Player {} starting position: ", idx + 1);
+        let field = line.strip_prefix(&prefix)?.trim();
+        *position = line.field(field)?;
+        if !(1..=10).contains(position) {
+            let message = format!("starting position {} is off the board", position);
+            return Err(line.error(field, ErrorKind::Invalid(message)));
+        }
+    }
+
+    Ok(Game::new(positions[0], positions[1]))
+}
+
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Game;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        load(input)
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        let mut game = input.clone();
+        Ok(Some(game.run().into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        let wins = input.count_wins();
+        Ok(Some(u64::max(wins[0], wins[1]).into()))
+    }
+}
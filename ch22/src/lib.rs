@@ -0,0 +1,204 @@
+mod volume;
+
+use std::collections::HashMap;
+use aoc_input::{ErrorKind, Line, Source};
+use common::{PartResult, Solution};
+
+pub use volume::Volume;
+
+/// An inclusive range of coordinates along one axis
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Range {
+    start: i64,
+    end: i64,
+}
+
+impl Range {
+    pub fn from_i64(from: i64, to: i64) -> Range {
+        Range {
+            start: from,
+            end: to,
+        }
+    }
+
+    fn intersect(&self, other: &Range) -> Option<Range> {
+        let start = i64::max(self.start, other.start);
+        let end = i64::min(self.end, other.end);
+        if start <= end {
+            Some(Range { start, end })
+        } else {
+            None
+        }
+    }
+
+    fn len(&self) -> i128 {
+        self.end as i128 - self.start as i128 + 1
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Cuboid {
+    x: Range,
+    y: Range,
+    z: Range,
+}
+
+impl Cuboid {
+    pub fn new(x: Range, y: Range, z: Range) -> Cuboid {
+        Cuboid { x, y, z }
+    }
+
+    /// A cube spanning `from..=to` along every axis
+    pub fn cube(from: i64, to: i64) -> Cuboid {
+        let range = Range::from_i64(from, to);
+        Cuboid::new(range, range, range)
+    }
+
+    pub fn intersect(&self, other: &Cuboid) -> Option<Cuboid> {
+        Some(Cuboid {
+            x: self.x.intersect(&other.x)?,
+            y: self.y.intersect(&other.y)?,
+            z: self.z.intersect(&other.z)?,
+        })
+    }
+
+    pub fn volume(&self) -> Volume {
+        Volume::from(self.x.len()) * Volume::from(self.y.len()) * Volume::from(self.z.len())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    state: bool,
+    cuboid: Cuboid,
+}
+
+/// The set of lit cubes, kept as cuboids that are each counted with a
+/// weight. Turning a cuboid on or off first cancels out its overlap with
+/// everything already there, so the weights always add up to 1 for lit
+/// cubes and 0 for the rest, however large the coordinates are.
+#[derive(Debug, Clone, Default)]
+pub struct Reactor {
+    cuboids: HashMap<Cuboid, i64>,
+}
+
+impl Reactor {
+    pub fn new() -> Reactor {
+        Reactor::default()
+    }
+
+    pub fn apply(&mut self, step: &Input) {
+        let mut changes: HashMap<Cuboid, i64> = HashMap::new();
+        for (cuboid, &weight) in self.cuboids.iter() {
+            if let Some(overlap) = cuboid.intersect(&step.cuboid) {
+                *changes.entry(overlap).or_insert(0) -= weight;
+            }
+        }
+        if step.state {
+            *changes.entry(step.cuboid).or_insert(0) += 1;
+        }
+
+        for (cuboid, change) in changes {
+            let weight = self.cuboids.entry(cuboid).or_insert(0);
+            *weight += change;
+            if *weight == 0 {
+                self.cuboids.remove(&cuboid);
+            }
+        }
+    }
+
+    /// Number of lit cubes
+    pub fn count(&self) -> Volume {
+        self.cuboids
+            .iter()
+            .map(|(cuboid, &weight)| cuboid.volume() * Volume::from(weight as i128))
+            .sum()
+    }
+
+    /// Number of lit cubes inside the given region
+    pub fn count_within(&self, region: &Cuboid) -> Volume {
+        self.cuboids
+            .iter()
+            .filter_map(|(cuboid, &weight)| {
+                Some(cuboid.intersect(region)?.volume() * Volume::from(weight as i128))
+            })
+            .sum()
+    }
+}
+
+fn run(input: &[Input]) -> Reactor {
+    let mut reactor = Reactor::new();
+    for step in input {
+        reactor.apply(step);
+    }
+    reactor
+}
+
+fn load_range(line: &Line, field: &str, axis: &str) -> aoc_input::Result<Range> {
+    let bounds = field
+        .strip_prefix(axis)
+        .ok_or_else(|| line.error(field, ErrorKind::Expected(axis.to_string())))?;
+    let (from, to) = bounds
+        .split_once("..")
+        .ok_or_else(|| line.error(bounds, ErrorKind::Expected("..".to_string())))?;
+
+    let range = Range::from_i64(line.field(from)?, line.field(to)?);
+    if range.start > range.end {
+        let message = format!("range {}..{} is backwards", range.start, range.end);
+        return Err(line.error(field, ErrorKind::Invalid(message)));
+    }
+
+    Ok(range)
+}
+
+fn load(input: &Source) -> aoc_input::Result<Vec<Input>> {
+    let mut output: Vec<Input> = vec![];
+    for line in input.lines() {
+        let (command, coords) = line.split_once(" ")?;
+
+        let state = match command {
+            "on" => true,
+            "off" => false,
+            _ => {
+                let message = format!("unknown command '{}'", command);
+                return Err(line.error(command, ErrorKind::Invalid(message)));
+            }
+        };
+
+        let coords: Vec<&str> = coords.trim().split(',').collect();
+        if coords.len() != 3 {
+            let message = "expected x, y and z ranges".to_string();
+            return Err(line.error_at(0, ErrorKind::Invalid(message)));
+        }
+
+        let cuboid = Cuboid::new(
+            load_range(&line, coords[0], "x=")?,
+            load_range(&line, coords[1], "y=")?,
+            load_range(&line, coords[2], "z=")?,
+        );
+        output.push(Input { state, cuboid });
+    }
+
+    Ok(output)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Input>;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        load(input)
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        //  Only the initialization region
+        let reactor = run(input);
+        Ok(Some(reactor.count_within(&Cuboid::cube(-50, 50)).into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        let reactor = run(input);
+        Ok(Some(reactor.count().into()))
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, Mul, Neg};
+use common::Answer;
+
+/// A number of cubes. A cuboid spanning the whole i64 range along every
+/// axis holds 2^192 of them, so this is a 256 bit integer in two's
+/// complement, least significant limb first. Arithmetic wraps, which
+/// keeps a sum of weighted volumes exact whenever the total fits, even
+/// if the partial sums along the way don't.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Volume([u64; 4]);
+
+impl Volume {
+    pub const ZERO: Volume = Volume([0; 4]);
+
+    fn is_negative(&self) -> bool {
+        self.0[3] >> 63 == 1
+    }
+
+    /// The volume if it fits in an i128
+    pub fn to_i128(&self) -> Option<i128> {
+        let value = (self.0[0] as u128 | (self.0[1] as u128) << 64) as i128;
+        if Volume::from(value) == *self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+impl From<i128> for Volume {
+    fn from(value: i128) -> Volume {
+        let fill = if value < 0 { u64::MAX } else { 0 };
+        Volume([value as u64, (value >> 64) as u64, fill, fill])
+    }
+}
+
+impl Add for Volume {
+    type Output = Volume;
+
+    fn add(self, other: Volume) -> Volume {
+        let mut limbs = [0u64; 4];
+        let mut carry = false;
+        for (idx, limb) in limbs.iter_mut().enumerate() {
+            let (sum, first) = self.0[idx].overflowing_add(other.0[idx]);
+            let (sum, second) = sum.overflowing_add(carry as u64);
+            *limb = sum;
+            carry = first || second;
+        }
+        Volume(limbs)
+    }
+}
+
+impl Neg for Volume {
+    type Output = Volume;
+
+    fn neg(self) -> Volume {
+        Volume(self.0.map(|limb| !limb)) + Volume::from(1)
+    }
+}
+
+impl Mul for Volume {
+    type Output = Volume;
+
+    fn mul(self, other: Volume) -> Volume {
+        //  Long multiplication, dropping everything past the top limb
+        let mut limbs = [0u64; 4];
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry: u128 = 0;
+            for (j, &b) in other.0.iter().take(4 - i).enumerate() {
+                let current = limbs[i + j] as u128 + a as u128 * b as u128 + carry;
+                limbs[i + j] = current as u64;
+                carry = current >> 64;
+            }
+        }
+        Volume(limbs)
+    }
+}
+
+impl Sum for Volume {
+    fn sum<I: Iterator<Item = Volume>>(iter: I) -> Volume {
+        iter.fold(Volume::ZERO, Add::add)
+    }
+}
+
+impl fmt::Display for Volume {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut magnitude = if self.is_negative() { (-*self).0 } else { self.0 };
+
+        //  Digits come out 19 at a time, the most that fit in a limb
+        const CHUNK: u128 = 10_000_000_000_000_000_000;
+        let mut chunks: Vec<u64> = vec![];
+        while magnitude != [0; 4] || chunks.is_empty() {
+            let mut remainder: u128 = 0;
+            for limb in magnitude.iter_mut().rev() {
+                let current = remainder << 64 | *limb as u128;
+                *limb = (current / CHUNK) as u64;
+                remainder = current % CHUNK;
+            }
+            chunks.push(remainder as u64);
+        }
+
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", chunks[chunks.len() - 1])?;
+        for chunk in chunks.iter().rev().skip(1) {
+            write!(f, "{:019}", chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Volume {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Volumes too large for a number are given as their digits
+impl From<Volume> for Answer {
+    fn from(volume: Volume) -> Answer {
+        match volume.to_i128() {
+            Some(value) => Answer::Number(value),
+            None => Answer::Text(volume.to_string()),
+        }
+    }
+}
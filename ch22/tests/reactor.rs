@@ -0,0 +1,76 @@
+use ch22::{Cuboid, Day22, Reactor, Volume};
+use common::{Answer, Solution};
+
+fn reactor(steps: &str) -> Reactor {
+    let mut reactor = Reactor::new();
+    for step in Day22::parse_str(steps).unwrap().iter() {
+        reactor.apply(step);
+    }
+    reactor
+}
+
+#[test]
+fn far_coordinates() {
+    let reactor = reactor(
+        "on x=-9000000000000000000..8999999999999999999,y=0..9,z=-5..4\n\
+         off x=-10..9,y=-100..100,z=0..0\n\
+         on x=-10..-1,y=5..5,z=0..0\n",
+    );
+
+    let full = 18_000_000_000_000_000_000i128 * 10 * 10;
+    assert_eq!(reactor.count(), Volume::from(full - 20 * 10 + 10));
+
+    //  The region only cuts the long slab short along x
+    let region = Cuboid::cube(-50, 50);
+    assert_eq!(reactor.count_within(&region), Volume::from(101 * 10 * 10 - 20 * 10 + 10));
+}
+
+#[test]
+fn overlapping_steps_cancel() {
+    let reactor = reactor(
+        "on x=0..9,y=0..9,z=0..9\n\
+         on x=5..14,y=5..14,z=5..14\n\
+         off x=0..14,y=0..14,z=0..14\n\
+         on x=3..3,y=3..3,z=3..3\n",
+    );
+    assert_eq!(reactor.count(), Volume::from(1));
+}
+
+fn part2(steps: &str) -> Answer {
+    Day22::part2(&Day22::parse_str(steps).unwrap()).unwrap().unwrap()
+}
+
+#[test]
+fn full_range() {
+    let steps = "on x=-9223372036854775808..9223372036854775807,\
+                 y=-9223372036854775808..9223372036854775807,z=0..0\n";
+    assert_eq!(part2(steps), Answer::Text("340282366920938463463374607431768211456".to_string()));
+
+    //  Switching most of it back off brings the count back in range
+    let steps = format!("{}off x=-9223372036854775808..9223372036854775806,\
+                         y=-9223372036854775808..9223372036854775807,z=0..0\n", steps);
+    assert_eq!(part2(&steps), Answer::Number(1 << 64));
+}
+
+#[test]
+fn full_range_on_every_axis() {
+    let steps = "on x=-9000000000000000000..9000000000000000000,\
+                 y=-9000000000000000000..9000000000000000000,\
+                 z=-9000000000000000000..9000000000000000000\n\
+                 off x=0..0,y=0..0,z=0..0\n";
+    let expected = "5832000000000000000972000000000000000054000000000000000000";
+    assert_eq!(part2(steps), Answer::Text(expected.to_string()));
+}
+
+#[test]
+fn volume_arithmetic() {
+    let big = Volume::from(i128::MAX);
+    assert_eq!(big.to_i128(), Some(i128::MAX));
+    assert_eq!((big + Volume::from(1)).to_i128(), None);
+    assert_eq!((big + Volume::from(1)).to_string(), "170141183460469231731687303715884105728");
+    assert_eq!((-big * big).to_string(),
+               "-28948022309329048855892746252171976962977213799489202546401021394546514198529");
+    assert_eq!(Volume::from(-42).to_string(), "-42");
+    assert_eq!(Volume::ZERO.to_string(), "0");
+    assert_eq!((Volume::from(-3) * Volume::from(-7)).to_i128(), Some(21));
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use aoc_input::{ErrorKind, Source};
+use common::{PartResult, Solution};
+
+fn find_rates(values: &(usize, Vec<u64>)) -> (u64, u64) {
+    let mut counts: Vec<i64> = vec![0; values.0];
+
+    let width = counts.len();
+    for v in &values.1 {
+        for (i, count) in counts.iter_mut().enumerate() {
+            let scalar: i64 = if (v & (1 << (width - i - 1))) != 0 {
+                1
+            } else {
+                -1
+            };
+            *count += scalar;
+        }
+    }
+
+    let mut gamma = 0;
+    for v in &counts {
+        gamma <<= 1;
+        if v > &0i64 {
+            gamma |= 0b1;
+        }
+    }
+
+    (gamma, !gamma & ((1 << counts.len()) - 1))
+}
+
+fn find_rating(input: &[String], inverted_logic: bool) -> u64 {
+    let ones_filter = if inverted_logic { '0' } else { '1' };
+    let zeros_filter = if inverted_logic { '1' } else { '0' };
+
+    let mut current = input.to_vec();
+    let mut position: usize = 0;
+    loop {
+        if current.len() == 1 {
+            return u64::from_str_radix(current[0].as_str(), 2).unwrap();
+        }
+
+        let ones = current
+            .iter()
+            .filter(|x| x.chars().nth(position).unwrap() == '1')
+            .count();
+        let zeroes = current.len() - ones;
+
+        let filter = if ones >= zeroes { ones_filter } else { zeros_filter };
+        let new_values: Vec<String> = current
+            .into_iter()
+            .filter(|x| x.chars().nth(position).unwrap() == filter)
+            .collect();
+        current = new_values;
+        position += 1;
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        //  Only used for validation, the parts work on the raw strings
+        let (_, width) = input.grid(|ch| match ch {
+            '0' | '1' => Some(()),
+            _ => None
+        })?;
+        let lines = input.lines();
+        if width >= 64 {
+            let message = format!("numbers of {} bits do not fit in 64 bits", width);
+            return Err(lines[0].error_at(0, ErrorKind::Invalid(message)));
+        }
+
+        //  The ratings narrow down to a single number, which two equal
+        //  rows would never do
+        let mut seen: HashMap<&str, usize> = HashMap::new();
+        for line in lines.iter() {
+            if let Some(first) = seen.insert(line.text(), line.number()) {
+                let message = format!("same number as line {}", first);
+                return Err(line.error_at(0, ErrorKind::Invalid(message)));
+            }
+        }
+
+        Ok(lines
+            .iter()
+            .map(
+                |l| l.text().to_string()
+            )
+            .collect())
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        let size = input[0].len();
+        let values: Vec<u64> = input
+            .iter()
+            .map(|line| u64::from_str_radix(line.as_str(), 2).unwrap())
+            .collect();
+
+        let (gamma, epsilon) = find_rates(&(size, values));
+        Ok(Some((gamma * epsilon).into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        let generator = find_rating(input, false);
+        let scrubber = find_rating(input, true);
+        Ok(Some((generator * scrubber).into()))
+    }
+}
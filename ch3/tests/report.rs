@@ -0,0 +1,15 @@
+use ch3::Day3;
+use common::Solution;
+
+#[test]
+fn parse_rejects_repeated_numbers() {
+    let error = Day3::parse_str("0101\n1100\n0101\n").unwrap_err();
+    assert_eq!(error.to_string(), "<input>:3: same number as line 1");
+}
+
+#[test]
+fn single_number() {
+    let input = Day3::parse_str("0110\n").unwrap();
+    assert_eq!(Day3::part1(&input), Ok(Some(54.into())));
+    assert_eq!(Day3::part2(&input), Ok(Some(36.into())));
+}
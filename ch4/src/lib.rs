@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+use aoc_input::{ErrorKind, Source};
+use common::{Grid, PartResult, Solution};
+
+/// Which marked cells make a board win
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct Rules {
+    /// Both diagonals of a square board also count as lines
+    pub diagonals: bool,
+    /// Only a fully marked board wins, lines don't count
+    pub full_card: bool
+}
+
+/// The marked cells of a board. Boards of up to 64 or 128 cells fit in
+/// a single integer, anything larger falls back to a `BitSet`.
+trait Bits: Clone {
+    fn empty(len: usize) -> Self;
+    fn set(&mut self, idx: usize);
+    fn is_set(&self, idx: usize) -> bool;
+    /// Whether every bit set in `mask` is also set here
+    fn covers(&self, mask: &Self) -> bool;
+}
+
+impl Bits for u64 {
+    fn empty(_len: usize) -> Self {
+        0
+    }
+
+    fn set(&mut self, idx: usize) {
+        *self |= 1 << idx;
+    }
+
+    fn is_set(&self, idx: usize) -> bool {
+        *self & (1 << idx) != 0
+    }
+
+    fn covers(&self, mask: &Self) -> bool {
+        *self & mask == *mask
+    }
+}
+
+impl Bits for u128 {
+    fn empty(_len: usize) -> Self {
+        0
+    }
+
+    fn set(&mut self, idx: usize) {
+        *self |= 1 << idx;
+    }
+
+    fn is_set(&self, idx: usize) -> bool {
+        *self & (1 << idx) != 0
+    }
+
+    fn covers(&self, mask: &Self) -> bool {
+        *self & mask == *mask
+    }
+}
+
+#[derive(Debug, Clone)]
+struct BitSet(Vec<u64>);
+
+impl Bits for BitSet {
+    fn empty(len: usize) -> Self {
+        BitSet(vec![0; len.div_ceil(64)])
+    }
+
+    fn set(&mut self, idx: usize) {
+        self.0[idx / 64] |= 1 << (idx % 64);
+    }
+
+    fn is_set(&self, idx: usize) -> bool {
+        self.0[idx / 64] & (1 << (idx % 64)) != 0
+    }
+
+    fn covers(&self, mask: &Self) -> bool {
+        self.0.iter().zip(&mask.0).all(|(bits, mask)| bits & mask == *mask)
+    }
+}
+
+struct Board<B: Bits> {
+    numbers: Grid<u64>,
+    marked: B,
+    /// Rows, then columns, then the two diagonals of square boards
+    lines: Vec<B>,
+    full: B
+}
+
+impl<B: Bits> Board<B> {
+    pub fn new(numbers: &Grid<u64>) -> Board<B> {
+        let (width, height) = (numbers.width(), numbers.height());
+        let line = |cells: &mut dyn Iterator<Item = (usize, usize)>| {
+            let mut mask = B::empty(numbers.len());
+            for (x, y) in cells {
+                mask.set(numbers.index_of(x, y));
+            }
+            mask
+        };
+
+        let mut lines: Vec<B> = vec![];
+        for y in 0..height {
+            lines.push(line(&mut (0..width).map(|x| (x, y))));
+        }
+        for x in 0..width {
+            lines.push(line(&mut (0..height).map(|y| (x, y))));
+        }
+        if width == height {
+            lines.push(line(&mut (0..width).map(|i| (i, i))));
+            lines.push(line(&mut (0..width).map(|i| (width - 1 - i, i))));
+        }
+
+        Board {
+            numbers: numbers.clone(),
+            marked: B::empty(numbers.len()),
+            lines,
+            full: line(&mut numbers.positions())
+        }
+    }
+
+    /// Whether marking the given cell completed a win. Only the lines
+    /// through that cell can have been completed by it.
+    pub fn check(&self, (x, y): (usize, usize), rules: &Rules) -> bool {
+        if rules.full_card {
+            return self.marked.covers(&self.full);
+        }
+
+        let (width, height) = (self.numbers.width(), self.numbers.height());
+        let mut candidates = vec![y, height + x];
+        if rules.diagonals && width == height {
+            if x == y {
+                candidates.push(height + width);
+            }
+            if x + y == width - 1 {
+                candidates.push(height + width + 1);
+            }
+        }
+
+        candidates
+            .into_iter()
+            .any(|line| self.marked.covers(&self.lines[line]))
+    }
+
+    pub fn mark(&mut self, index: usize, rules: &Rules) -> bool {
+        self.marked.set(index);
+
+        self.check(self.numbers.position_of(index), rules)
+    }
+
+    pub fn sum_unmarked(&self) -> u64 {
+        let mut sum: u64 = 0;
+
+        for (i, value) in self.numbers.cells().iter().enumerate() {
+            if self.marked.is_set(i) {
+                continue;
+            }
+
+            sum += value;
+        }
+
+        sum
+    }
+}
+
+/// The numbers that are drawn, in order, and every board
+#[derive(Debug, Clone)]
+pub struct Bingo {
+    pub draws: Vec<u64>,
+    pub boards: Vec<Grid<u64>>
+}
+
+fn load(input: &Source) -> aoc_input::Result<Bingo> {
+    let sections = input.sections();
+    let draws: Vec<u64> = match sections.first() {
+        Some(section) => section.line(0)?.comma_list()?,
+        None => return Err(input.section().end_error()),
+    };
+
+    let mut boards: Vec<Grid<u64>> = vec![];
+    for section in sections.iter().skip(1) {
+        let mut numbers: Vec<u64> = vec![];
+        let mut width = 0;
+
+        for (y, line) in section.lines().iter().enumerate() {
+            let row: Vec<u64> = line.fields()?;
+            if y == 0 {
+                width = row.len();
+            } else if row.len() != width {
+                let kind = ErrorKind::RaggedRow { expected: width, found: row.len() };
+                return Err(line.error_at(0, kind));
+            }
+
+            numbers.extend(row);
+        }
+
+        boards.push(Grid::new(numbers, width));
+    }
+
+    Ok(Bingo { draws, boards })
+}
+
+/// A board winning under the rules it is played with
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Win {
+    /// Index of the board in the input
+    pub board: usize,
+    /// The number that made the board win
+    pub draw: u64,
+    pub unmarked: u64,
+    pub score: u64
+}
+
+/// Plays the whole game, returning every board's win in the order they
+/// happen. Boards that win on the same draw are listed in input order,
+/// and a board stops playing once it has won.
+pub fn play(input: &Bingo, rules: &Rules) -> Vec<Win> {
+    let cells = input.boards.iter().map(|board| board.len()).max().unwrap_or(0);
+    if cells <= 64 {
+        play_with::<u64>(input, rules)
+    } else if cells <= 128 {
+        play_with::<u128>(input, rules)
+    } else {
+        play_with::<BitSet>(input, rules)
+    }
+}
+
+/// Maps every number to the cells holding it, as (board, cell index)
+/// pairs in input order, so a draw only visits the boards it hits
+fn index(boards: &[Grid<u64>]) -> HashMap<u64, Vec<(usize, usize)>> {
+    let mut index: HashMap<u64, Vec<(usize, usize)>> = HashMap::new();
+    for (board, numbers) in boards.iter().enumerate() {
+        for (cell, number) in numbers.cells().iter().enumerate() {
+            index.entry(*number).or_default().push((board, cell));
+        }
+    }
+
+    index
+}
+
+fn play_with<B: Bits>(input: &Bingo, rules: &Rules) -> Vec<Win> {
+    let mut boards: Vec<Board<B>> = input.boards
+        .iter()
+        .map(Board::new)
+        .collect();
+    let mut won = vec![false; boards.len()];
+    let index = index(&input.boards);
+
+    let mut wins: Vec<Win> = vec![];
+    for number in &input.draws {
+        let hits = match index.get(number) {
+            Some(hits) => hits,
+            None => continue,
+        };
+
+        //  Hits come grouped by board. Every cell of a board holding the
+        //  number is marked before its win is scored.
+        for board_hits in hits.chunk_by(|a, b| a.0 == b.0) {
+            let idx = board_hits[0].0;
+            if won[idx] {
+                continue;
+            }
+
+            let board = &mut boards[idx];
+            let mut completed = false;
+            for &(_, cell) in board_hits {
+                completed |= board.mark(cell, rules);
+            }
+
+            if completed {
+                let unmarked = board.sum_unmarked();
+                won[idx] = true;
+                wins.push(Win {
+                    board: idx,
+                    draw: *number,
+                    unmarked,
+                    score: unmarked * number
+                });
+            }
+        }
+
+        if wins.len() == boards.len() {
+            break;
+        }
+    }
+
+    wins
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Bingo;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        load(input)
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        Ok(play(input, &Rules::default()).first().map(|win| win.score.into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        Ok(play(input, &Rules::default()).last().map(|win| win.score.into()))
+    }
+}
@@ -0,0 +1,119 @@
+use ch4::{play, Bingo, Day4, Rules, Win};
+use common::{Grid, Solution};
+use aoc_input::Source;
+
+fn sample() -> <Day4 as Solution>::Input {
+    let path = format!("{}/sample.txt", env!("CARGO_MANIFEST_DIR"));
+    Day4::parse(&Source::open(path).unwrap()).unwrap()
+}
+
+/// A board numbered 0, 1, 2, ... row by row
+fn counting(width: usize, height: usize) -> Grid<u64> {
+    Grid::new((0..(width * height) as u64).collect(), width)
+}
+
+#[test]
+fn win_order() {
+    let wins = play(&sample(), &Rules::default());
+    let boards: Vec<usize> = wins.iter().map(|win| win.board).collect();
+    assert_eq!(boards, [2, 0, 1]);
+
+    assert_eq!(wins[0], Win { board: 2, draw: 24, unmarked: 188, score: 4512 });
+    assert_eq!(wins[2], Win { board: 1, draw: 13, unmarked: 148, score: 1924 });
+}
+
+#[test]
+fn diagonals() {
+    let bingo = Bingo { draws: vec![0, 6, 12, 18, 24], boards: vec![counting(5, 5)] };
+    assert!(play(&bingo, &Rules::default()).is_empty());
+
+    let rules = Rules { diagonals: true, ..Rules::default() };
+    let wins = play(&bingo, &rules);
+    assert_eq!(wins, [Win { board: 0, draw: 24, unmarked: 240, score: 5760 }]);
+
+    let anti = Bingo { draws: vec![4, 8, 12, 16, 20], boards: vec![counting(5, 5)] };
+    assert_eq!(play(&anti, &rules)[0].draw, 20);
+}
+
+#[test]
+fn diagonals_need_square_boards() {
+    let bingo = Bingo { draws: vec![0, 5, 10], boards: vec![counting(4, 3)] };
+    let rules = Rules { diagonals: true, ..Rules::default() };
+    assert!(play(&bingo, &rules).is_empty());
+}
+
+#[test]
+fn full_card() {
+    let bingo = Bingo { draws: (0..9).collect(), boards: vec![counting(3, 3), counting(2, 2)] };
+    assert_eq!(play(&bingo, &Rules::default())[0].draw, 1);
+
+    let rules = Rules { full_card: true, ..Rules::default() };
+    let wins = play(&bingo, &rules);
+    assert_eq!(wins[0], Win { board: 1, draw: 3, unmarked: 0, score: 0 });
+    assert_eq!(wins[1], Win { board: 0, draw: 8, unmarked: 0, score: 0 });
+}
+
+#[test]
+fn rectangular_boards() {
+    //  One row of the first board, one column of the second
+    let bingo = Bingo {
+        draws: vec![4, 5, 6, 7, 1, 10, 13],
+        boards: vec![counting(4, 3), counting(3, 5)],
+    };
+
+    let wins = play(&bingo, &Rules::default());
+    assert_eq!(wins[0], Win { board: 0, draw: 7, unmarked: 44, score: 308 });
+    assert_eq!(wins[1], Win { board: 1, draw: 13, unmarked: 59, score: 767 });
+}
+
+#[test]
+fn large_boards() {
+    //  Fits in a u128, then needs more than one
+    for size in [10, 20] {
+        let last = (size * size) as u64 - 1;
+        let draws: Vec<u64> = (0..size as u64).map(|x| last - x).collect();
+        let bingo = Bingo { draws, boards: vec![counting(size, size)] };
+
+        let wins = play(&bingo, &Rules::default());
+        let total: u64 = (0..=last).sum();
+        let row: u64 = (0..size as u64).map(|x| last - x).sum();
+        assert_eq!(wins.len(), 1);
+        assert_eq!(wins[0].unmarked, total - row);
+    }
+}
+
+#[test]
+fn ragged_board() {
+    let error = Day4::parse_str("1,2\n\n1 2\n3\n").unwrap_err();
+    assert_eq!((error.line, error.column), (4, 0));
+}
+
+#[test]
+fn many_boards() {
+    let boards = vec![counting(5, 5); 20_000];
+    let bingo = Bingo { draws: vec![99, 0, 1, 2, 3, 4, 5], boards };
+
+    let wins = play(&bingo, &Rules::default());
+    assert_eq!(wins.len(), 20_000);
+    assert!(wins.iter().enumerate().all(|(idx, win)| win.board == idx && win.draw == 4));
+}
+
+#[test]
+fn repeated_numbers() {
+    //  The diagonal is only complete once both cells holding 1 are marked
+    let board = Grid::new(vec![1, 2, 3, 1], 2);
+    let bingo = Bingo { draws: vec![1], boards: vec![board] };
+
+    let rules = Rules { diagonals: true, ..Rules::default() };
+    assert_eq!(play(&bingo, &rules), [Win { board: 0, draw: 1, unmarked: 5, score: 5 }]);
+}
+
+#[test]
+fn repeated_numbers_first_wins() {
+    //  The first 1 completes the top row, but the second is still marked
+    //  before the board is scored
+    let board = Grid::new(vec![5, 1, 2, 1], 2);
+    let bingo = Bingo { draws: vec![5, 1], boards: vec![board] };
+
+    assert_eq!(play(&bingo, &Rules::default()), [Win { board: 0, draw: 1, unmarked: 2, score: 2 }]);
+}
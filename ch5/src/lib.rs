@@ -0,0 +1,268 @@
+use std::collections::{HashMap, HashSet};
+use aoc_input::{ErrorKind, Line, Source};
+use common::{PartResult, Point, Solution};
+
+#[derive(Debug, Copy, Clone)]
+pub struct Segment {
+    from: Point,
+    to: Point
+}
+
+/// The ways a segment can run. Every line running a given way is told
+/// apart by `Direction::line_of`, and points along it by
+/// `Direction::along`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Direction {
+    Horizontal,
+    Vertical,
+    /// Down and to the right, or the other way round
+    Diagonal,
+    /// Up and to the right, or the other way round
+    AntiDiagonal
+}
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Horizontal,
+    Direction::Vertical,
+    Direction::Diagonal,
+    Direction::AntiDiagonal
+];
+
+impl Direction {
+    /// `(a, b)` such that `a * x + b * y` is the same for every point on
+    /// one line
+    fn normal(self) -> (i64, i64) {
+        match self {
+            Direction::Horizontal => (0, 1),
+            Direction::Vertical => (1, 0),
+            Direction::Diagonal => (1, -1),
+            Direction::AntiDiagonal => (1, 1)
+        }
+    }
+
+    fn line_of(self, point: Point) -> i64 {
+        let (a, b) = self.normal();
+        a * point.x + b * point.y
+    }
+
+    fn along(self, point: Point) -> i64 {
+        match self {
+            Direction::Vertical => point.y,
+            _ => point.x
+        }
+    }
+
+    /// The grid point where a line running this way meets one running
+    /// `other` way, if they meet on one. Diagonals of both kinds can
+    /// cross between grid points.
+    fn crossing(self, line: i64, other: Direction, other_line: i64) -> Option<Point> {
+        let (a1, b1) = self.normal();
+        let (a2, b2) = other.normal();
+        let det = a1 * b2 - a2 * b1;
+        let x = line * b2 - b1 * other_line;
+        let y = a1 * other_line - a2 * line;
+        if x % det != 0 || y % det != 0 {
+            return None;
+        }
+
+        Some(Point::new(x / det, y / det))
+    }
+}
+
+/// A segment as the stretch `from..=to` along one line
+#[derive(Debug, Copy, Clone)]
+struct Run {
+    direction: Direction,
+    line: i64,
+    from: i64,
+    to: i64
+}
+
+impl Run {
+    fn new(segment: &Segment) -> Run {
+        let dx = segment.to.x - segment.from.x;
+        let dy = segment.to.y - segment.from.y;
+        let direction = if dx == 0 {
+            Direction::Vertical
+        } else if dy == 0 {
+            Direction::Horizontal
+        } else if (dx > 0) == (dy > 0) {
+            Direction::Diagonal
+        } else {
+            Direction::AntiDiagonal
+        };
+
+        let (from, to) = (direction.along(segment.from), direction.along(segment.to));
+        Run {
+            direction,
+            line: direction.line_of(segment.from),
+            from: from.min(to),
+            to: from.max(to)
+        }
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        let along = self.direction.along(point);
+        self.from <= along && along <= self.to
+    }
+}
+
+impl Segment {
+    fn is_axis_aligned(&self) -> bool {
+        self.from.x == self.to.x || self.from.y == self.to.y
+    }
+}
+
+/// The stretches of one line covered by more than one of `runs`, sorted
+/// and not touching each other
+fn shared_stretches(runs: &mut [(i64, i64)]) -> Vec<(i64, i64)> {
+    runs.sort_unstable();
+
+    let mut shared: Vec<(i64, i64)> = vec![];
+    let mut reach = i64::MIN;
+    for &(from, to) in runs.iter() {
+        if from <= reach {
+            let stretch = (from, to.min(reach));
+            match shared.last_mut() {
+                Some(last) if stretch.0 <= last.1 + 1 => last.1 = last.1.max(stretch.1),
+                _ => shared.push(stretch)
+            }
+        }
+        reach = reach.max(to);
+    }
+
+    shared
+}
+
+/// Counts the points covered by at least two segments, without visiting
+/// the points themselves, so it takes no longer for long segments.
+///
+/// Segments on the same line share whole stretches of it, found by
+/// sweeping along each line. Segments on different lines share at most
+/// the one point where they cross. A crossing may also be inside shared
+/// stretches, on up to one line running each way, and is only counted
+/// once.
+fn count_overlaps(input: &[Segment], diagonals: bool) -> usize {
+    let runs: Vec<Run> = input
+        .iter()
+        .filter(|segment| diagonals || segment.is_axis_aligned())
+        .map(Run::new)
+        .collect();
+
+    let mut lines: HashMap<(Direction, i64), Vec<(i64, i64)>> = HashMap::new();
+    for run in &runs {
+        lines.entry((run.direction, run.line)).or_default().push((run.from, run.to));
+    }
+
+    let shared: HashMap<(Direction, i64), Vec<(i64, i64)>> = lines
+        .into_iter()
+        .map(|(line, mut runs)| (line, shared_stretches(&mut runs)))
+        .filter(|(_, stretches)| !stretches.is_empty())
+        .collect();
+
+    let mut crossings: HashSet<Point> = HashSet::new();
+    for (idx, first) in runs.iter().enumerate() {
+        for second in &runs[idx + 1..] {
+            if first.direction == second.direction {
+                continue;
+            }
+
+            let point = match first.direction.crossing(first.line, second.direction, second.line) {
+                Some(point) => point,
+                None => continue
+            };
+            if first.contains(point) && second.contains(point) {
+                crossings.insert(point);
+            }
+        }
+    }
+
+    let in_shared = |direction: Direction, point: Point| {
+        let stretches = match shared.get(&(direction, direction.line_of(point))) {
+            Some(stretches) => stretches,
+            None => return false
+        };
+        let along = direction.along(point);
+        let idx = stretches.partition_point(|&(_, to)| to < along);
+        stretches.get(idx).is_some_and(|&(from, _)| from <= along)
+    };
+
+    let mut count: usize = shared
+        .values()
+        .flatten()
+        .map(|(from, to)| (to - from) as usize + 1)
+        .sum();
+    for point in crossings {
+        match DIRECTIONS.iter().filter(|&&direction| in_shared(direction, point)).count() {
+            0 => count += 1,
+            lines => count -= lines - 1
+        }
+    }
+
+    count
+}
+
+/// Coordinates are kept small enough that working out which line a point
+/// is on can't overflow
+pub const MAX_COORDINATE: i64 = 1 << 60;
+
+fn load_coordinate(line: &Line, field: &str) -> aoc_input::Result<i64> {
+    let value: i64 = line.field(field)?;
+    if value.abs() > MAX_COORDINATE {
+        let message = format!("coordinate {} is further than {} from 0", value, MAX_COORDINATE);
+        return Err(line.error(field, ErrorKind::Invalid(message)));
+    }
+
+    Ok(value)
+}
+
+fn load_point(line: &Line, field: &str) -> aoc_input::Result<Point> {
+    let (x, y) = field
+        .split_once(',')
+        .ok_or_else(|| line.error(field, ErrorKind::Expected(",".to_string())))?;
+
+    Ok(Point {
+        x: load_coordinate(line, x.trim())?,
+        y: load_coordinate(line, y.trim())?
+    })
+}
+
+fn load(input: &Source) -> aoc_input::Result<Vec<Segment>> {
+    let mut segments: Vec<Segment> = vec![];
+    for line in input.lines() {
+        let (from, to) = line.split_once("->")?;
+        let segment = Segment {
+            from: load_point(&line, from.trim())?,
+            to: load_point(&line, to.trim())?
+        };
+
+        let dx = (segment.to.x - segment.from.x).abs();
+        let dy = (segment.to.y - segment.from.y).abs();
+        if !segment.is_axis_aligned() && dx != dy {
+            let message = "segment is neither horizontal, vertical nor at 45 degrees".to_string();
+            return Err(line.error_at(0, ErrorKind::Invalid(message)));
+        }
+
+        segments.push(segment);
+    }
+
+    Ok(segments)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<Segment>;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        load(input)
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        Ok(Some(count_overlaps(input, false).into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        Ok(Some(count_overlaps(input, true).into()))
+    }
+}
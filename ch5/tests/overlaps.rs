@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use ch5::Day5;
+use common::{Answer, Solution};
+
+fn answers(text: &str) -> [Option<Answer>; 2] {
+    let input = Day5::parse_str(text).unwrap();
+    [Day5::part1(&input).unwrap(), Day5::part2(&input).unwrap()]
+}
+
+fn counts(part1: i128, part2: i128) -> [Option<Answer>; 2] {
+    [Some(Answer::Number(part1)), Some(Answer::Number(part2))]
+}
+
+/// Counts by visiting every point, for segments given as
+/// `(x1, y1, x2, y2)`
+fn brute_force(segments: &[(i64, i64, i64, i64)], diagonals: bool) -> i128 {
+    let mut covered: HashMap<(i64, i64), u32> = HashMap::new();
+    for &(x1, y1, x2, y2) in segments {
+        if !diagonals && x1 != x2 && y1 != y2 {
+            continue;
+        }
+
+        let (dx, dy) = ((x2 - x1).signum(), (y2 - y1).signum());
+        for step in 0..=i64::max((x2 - x1).abs(), (y2 - y1).abs()) {
+            *covered.entry((x1 + step * dx, y1 + step * dy)).or_insert(0) += 1;
+        }
+    }
+
+    covered.values().filter(|&&count| count > 1).count() as i128
+}
+
+#[test]
+fn large_coordinates() {
+    let text = "0,0 -> 1000000000000,0\n\
+                400000000000,0 -> 2000000000000,0\n\
+                500000000000,-7 -> 500000000000,7\n";
+    //  The two horizontal segments share 600000000001 points, one of
+    //  which the vertical one crosses
+    assert_eq!(answers(text), counts(600_000_000_001, 600_000_000_001));
+}
+
+#[test]
+fn diagonal_overlaps() {
+    //  Crossing diagonals, both ways round, and ones that don't meet on
+    //  a grid point
+    assert_eq!(answers("0,0 -> 4,4\n0,4 -> 4,0\n"), counts(0, 1));
+    assert_eq!(answers("0,0 -> 3,3\n0,3 -> 3,0\n"), counts(0, 0));
+
+    //  Collinear diagonals, one reversed, crossed by a horizontal line
+    //  inside the part they share
+    let text = "0,0 -> 10,10\n8,8 -> 3,3\n0,5 -> 10,5\n";
+    assert_eq!(answers(text), counts(0, 6));
+
+    //  Shared stretches on two lines that cross each other
+    let text = "0,5 -> 10,5\n2,5 -> 8,5\n5,0 -> 5,10\n5,2 -> 5,8\n";
+    assert_eq!(answers(text), counts(13, 13));
+}
+
+#[test]
+fn agrees_with_brute_force() {
+    //  A fixed spread of short segments of every kind, many of which
+    //  share points
+    let mut segments: Vec<(i64, i64, i64, i64)> = vec![];
+    let mut seed: i64 = 7;
+    let mut next = |bound: i64| {
+        seed = (seed * 1_103_515_245 + 12_345) % 2_147_483_648;
+        seed % bound
+    };
+    for _ in 0..300 {
+        let (x, y, length) = (next(40), next(40), next(15));
+        let (x2, y2) = match next(4) {
+            0 => (x + length, y),
+            1 => (x, y + length),
+            2 => (x + length, y + length),
+            _ => (x + length, y - length),
+        };
+        segments.push((x, y, x2, y2));
+    }
+
+    let text: String = segments
+        .iter()
+        .map(|(x1, y1, x2, y2)| format!("{},{} -> {},{}\n", x1, y1, x2, y2))
+        .collect();
+    assert_eq!(answers(&text), counts(brute_force(&segments, false), brute_force(&segments, true)));
+}
+
+#[test]
+fn malformed_lines() {
+    let error = Day5::parse_str("0,9 -> 5,9\n8,0 => 0,8\n").unwrap_err();
+    assert_eq!(error.line, 2);
+
+    let error = Day5::parse_str("0,9 -> 5 9\n").unwrap_err();
+    assert_eq!(error.to_string(), "<input>:1:8: expected ','");
+
+    let error = Day5::parse_str("0,9 -> 5,x\n").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 10));
+
+    let error = Day5::parse_str("0,0 -> 2,1\n").unwrap_err();
+    assert_eq!(error.to_string(), "<input>:1: segment is neither horizontal, vertical nor at 45 degrees");
+
+    let error = Day5::parse_str("0,0 -> 0,2000000000000000000\n").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 10));
+}
@@ -0,0 +1,54 @@
+use std::collections::VecDeque;
+use aoc_input::{ErrorKind, Source};
+use common::{PartResult, Solution};
+
+fn run_boxed(input: &[i8], days: usize) -> i64 {
+    //  Each element in the deque represents the amount of fish
+    //  with a timer value equal to the index, i.e.
+    //  counts[0] = count of fish with timer=0
+    let mut counts: VecDeque<i64> = VecDeque::new();
+    counts.resize(9, 0);
+    for time in input {
+        counts[*time as usize] += 1;
+    }
+
+    for _ in 0..days {
+        //  "Rotate" the counts deque to the left
+        //  Also remember the first element, which gets added to
+        //  the counts for timer value 6
+        let born = counts.pop_front().unwrap();
+        counts.push_back(born);
+        counts[6] += born;
+    }
+
+    let mut sum = 0;
+    for fish in counts {
+        sum += fish;
+    }
+
+    sum
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<i8>;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        let timers: Vec<i8> = input.comma_list()?;
+        if let Some(timer) = timers.iter().find(|&&t| !(0..=8).contains(&t)) {
+            let message = format!("timer value {} is outside of 0..=8", timer);
+            return Err(input.section().line(0)?.error_at(0, ErrorKind::Invalid(message)));
+        }
+
+        Ok(timers)
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        Ok(Some(run_boxed(input, 80).into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        Ok(Some(run_boxed(input, 256).into()))
+    }
+}
@@ -0,0 +1,112 @@
+use aoc_input::Source;
+use common::{PartResult, Solution};
+
+/// How much fuel a crab burns to move a given distance
+pub trait FuelCost {
+    fn cost(&self, distance: i64) -> i64;
+}
+
+/// Every step costs 1 fuel
+pub struct Linear;
+
+/// Each step costs 1 more than the previous one
+pub struct Triangular;
+
+impl FuelCost for Linear {
+    fn cost(&self, distance: i64) -> i64 {
+        distance
+    }
+}
+
+impl FuelCost for Triangular {
+    fn cost(&self, distance: i64) -> i64 {
+        distance * (distance + 1) / 2
+    }
+}
+
+impl<F: Fn(i64) -> i64> FuelCost for F {
+    fn cost(&self, distance: i64) -> i64 {
+        self(distance)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Alignment {
+    pub position: i64,
+    pub fuel: i64,
+}
+
+pub fn total_fuel<C: FuelCost + ?Sized>(crabs: &[i64], position: i64, cost: &C) -> i64 {
+    crabs
+        .iter()
+        .map(|&crab| cost.cost((crab - position).abs()))
+        .sum()
+}
+
+fn best_of<C: FuelCost + ?Sized>(crabs: &[i64], candidates: impl Iterator<Item = i64>, cost: &C) -> Alignment {
+    candidates
+        .map(|position| Alignment { position, fuel: total_fuel(crabs, position, cost) })
+        .min_by_key(|alignment| (alignment.fuel, alignment.position))
+        .expect("there is always a candidate")
+}
+
+/// Finds the cheapest position for any cost that is convex and never
+/// decreases with distance, as the total fuel is then convex in the
+/// position too. Binary searches for the point where the total stops
+/// going down. There is nothing to align without crabs.
+pub fn convex_search<C: FuelCost + ?Sized>(crabs: &[i64], cost: &C) -> Option<Alignment> {
+    let mut low = *crabs.iter().min()?;
+    let mut high = *crabs.iter().max()?;
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if total_fuel(crabs, mid, cost) <= total_fuel(crabs, mid + 1, cost) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    Some(Alignment { position: low, fuel: total_fuel(crabs, low, cost) })
+}
+
+/// With a linear cost the median is optimal
+pub fn align_linear(crabs: &[i64]) -> Option<Alignment> {
+    if crabs.is_empty() {
+        return None;
+    }
+
+    let mut sorted = crabs.to_vec();
+    sorted.sort_unstable();
+    let position = sorted[(sorted.len() - 1) / 2];
+
+    Some(Alignment { position, fuel: total_fuel(crabs, position, &Linear) })
+}
+
+/// With a triangular cost the optimum is within half a step of the mean
+pub fn align_triangular(crabs: &[i64]) -> Option<Alignment> {
+    if crabs.is_empty() {
+        return None;
+    }
+
+    let mean = crabs.iter().sum::<i64>().div_euclid(crabs.len() as i64);
+    Some(best_of(crabs, mean - 1..=mean + 1, &Triangular))
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<i64>;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        input.comma_list()
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        Ok(align_linear(input).map(|alignment| alignment.fuel.into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        Ok(align_triangular(input).map(|alignment| alignment.fuel.into()))
+    }
+}
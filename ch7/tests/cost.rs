@@ -0,0 +1,46 @@
+use ch7::{align_linear, align_triangular, convex_search, total_fuel, Alignment, Day7, FuelCost, Linear, Triangular};
+use common::Solution;
+
+const SAMPLE: [i64; 10] = [16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+
+#[test]
+fn sample_alignments() {
+    assert_eq!(align_linear(&SAMPLE), Some(Alignment { position: 2, fuel: 37 }));
+    assert_eq!(align_triangular(&SAMPLE), Some(Alignment { position: 5, fuel: 168 }));
+}
+
+#[test]
+fn convex_search_agrees() {
+    assert_eq!(convex_search(&SAMPLE, &Linear), align_linear(&SAMPLE));
+    assert_eq!(convex_search(&SAMPLE, &Triangular), align_triangular(&SAMPLE));
+}
+
+#[test]
+fn custom_cost() {
+    //  Squared distance is minimised at the mean, which is 4.9
+    let squared = |distance: i64| distance * distance;
+    assert_eq!(convex_search(&SAMPLE, &squared).map(|alignment| alignment.position), Some(5));
+
+    let cubed = |distance: i64| distance * distance * distance;
+    assert_eq!(convex_search(&SAMPLE, &cubed), Some(brute_force(&cubed)));
+}
+
+fn brute_force<C: FuelCost>(cost: &C) -> Alignment {
+    (0..=16)
+        .map(|position| Alignment { position, fuel: total_fuel(&SAMPLE, position, cost) })
+        .min_by_key(|alignment| alignment.fuel)
+        .unwrap()
+}
+
+
+#[test]
+fn no_crabs() {
+    assert_eq!(align_linear(&[]), None);
+    assert_eq!(align_triangular(&[]), None);
+    assert_eq!(convex_search(&[], &Linear), None);
+
+    let error = Day7::parse_str("\n").unwrap_err();
+    assert_eq!(error.to_string(), "<input>:1: unexpected end of input");
+    let error = Day7::parse_str("1,,2\n").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 3));
+}
@@ -1,7 +1,5 @@
-use std::borrow::Borrow;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::str::FromStr;
+use aoc_input::{ErrorKind, Line, Source};
+use common::{PartResult, Solution};
 
 enum Segment {
     A = 0x01,
@@ -13,23 +11,10 @@ enum Segment {
     G = 0x40,
 }
 
-fn load(filename: &str) -> Vec<String> {
-    let file = File::open(filename).expect("no such file");
-    let buf = BufReader::new(file);
-
-    let lines: Vec<String> = buf.lines()
-        .map(
-            |l| l.unwrap()
-        )
-        .collect();
-
-    lines
-}
-
-fn pattern_to_byte(pattern: &str) -> u8 {
+fn pattern_to_byte(line: &Line, pattern: &str) -> aoc_input::Result<u8> {
     let mut byte: u8 = 0x0;
 
-    for ch in pattern.chars() {
+    for (idx, ch) in pattern.char_indices() {
         let segtype = match ch {
             'a' => Segment::A,
             'b' => Segment::B,
@@ -39,96 +24,57 @@ fn pattern_to_byte(pattern: &str) -> u8 {
             'f' => Segment::F,
             'g' => Segment::G,
             _ => {
-                panic!();
+                return Err(line.error(&pattern[idx..], ErrorKind::InvalidChar(ch)));
             }
         };
 
         byte |= segtype as u8;
     }
 
-    byte
+    Ok(byte)
 }
 
-fn run_p1(input: &Vec<String>) {
+fn run_p1(input: &[(Vec<u8>, Vec<u8>)]) -> usize {
     let mut count = 0;
-    for line in input {
-        let parts = line.split_once('|').unwrap();
-        let patterns: Vec<&str> = parts.0
-            .split_whitespace()
-            .collect();
-        let outputs: Vec<&str> = parts.1
-            .split_whitespace()
-            .collect();
-
-        assert_eq!(patterns.len(), 10);
-        assert_eq!(outputs.len(), 4);
-
-        // dbg!(patterns.borrow());
-        dbg!(<Vec<&str> as Borrow<[&str]>>::borrow(outputs.borrow()));
-
+    for (_, outputs) in input {
         let unique = outputs
             .iter()
-            .filter(|p| {
-                match p.len() {
-                    2 => true,
-                    4 => true,
-                    3 => true,
-                    7 => true,
-                    _ => false
-                }
-            })
+            .filter(|p| matches!(p.count_ones(), 2 | 4 | 3 | 7))
             .count();
         count += unique;
     }
 
-    println!("Unique count: {}", count);
+    count
 }
 
-fn run_p2(input: &Vec<String>) {
+fn run_p2(input: &[(Vec<u8>, Vec<u8>)]) -> usize {
     let mut sum = 0;
 
-    for line in input {
-        let parts = line.split_once('|').unwrap();
-        let patterns: Vec<u8> = parts.0
-            .split_whitespace()
-            .map(pattern_to_byte)
-            .collect();
-        let outputs: Vec<u8> = parts.1
-            .split_whitespace()
-            .map(pattern_to_byte)
-            .collect();
-
-        assert_eq!(patterns.len(), 10);
-        assert_eq!(outputs.len(), 4);
-
+    for (patterns, outputs) in input {
         // dbg!(patterns);
         // dbg!(outputs);
 
         let one_pattern: u8 = patterns
             .iter()
             .find(|p| p.count_ones() == 2)
-            .unwrap()
-            .clone();
+            .copied()
+            .unwrap();
         let four_pattern: u8 = patterns
             .iter()
             .find(|p| p.count_ones() == 4)
-            .unwrap()
-            .clone();
+            .copied()
+            .unwrap();
         let seven_pattern: u8 = patterns
             .iter()
             .find(|p| p.count_ones() == 3)
-            .unwrap()
-            .clone();
+            .copied()
+            .unwrap();
         let eight_pattern: u8 = patterns
             .iter()
             .find(|p| p.count_ones() == 7)
-            .unwrap()
-            .clone();
+            .copied()
+            .unwrap();
 
-        println!("Pattern[1]: {:07b}", one_pattern);
-        println!("Pattern[4]: {:07b}", four_pattern);
-        println!("Pattern[7]: {:07b}", seven_pattern);
-        println!("Pattern[8]: {:07b}", eight_pattern);
 
         let seg_a = seven_pattern & !one_pattern;
         assert_eq!(seg_a.count_ones(), 1);
@@ -136,8 +82,6 @@ fn run_p2(input: &Vec<String>) {
         let seg_bd = four_pattern & !one_pattern;
         assert_eq!(seg_bd.count_ones(), 2);
 
-        println!("Segment[a]: {:07b}", seg_a);
-        println!("Segment[bd]: {:07b}", seg_bd);
 
         let nine_pattern = patterns
             .iter()
@@ -145,34 +89,28 @@ fn run_p2(input: &Vec<String>) {
                 p.count_ones() == 6 &&
                     (*p ^ (four_pattern | seg_a)).count_ones() == 1
             })
-            .unwrap()
-            .clone();
+            .copied()
+            .unwrap();
 
-        println!("Pattern[9]: {:07b}", nine_pattern);
 
         let seg_g = (four_pattern | seg_a) ^ nine_pattern;
         assert_eq!(seg_g.count_ones(), 1);
-        println!("Segment[g]: {:07b}", seg_g);
 
-        let seg_e = (nine_pattern ^ eight_pattern);
+        let seg_e = nine_pattern ^ eight_pattern;
         assert_eq!(seg_e.count_ones(), 1);
-        println!("Segment[e]: {:07b}", seg_e);
 
         let three_pattern = patterns
             .iter()
             .find(|p| {
                 p.count_ones() == 5 && ((*p & one_pattern) == one_pattern)
             })
-            .unwrap()
-            .clone();
-        println!("Pattern[3]: {:07b}", three_pattern);
+            .copied()
+            .unwrap();
 
         let seg_d = three_pattern & seg_bd;
         let seg_b = seg_bd & !seg_d;
         assert_eq!(seg_b.count_ones(), 1);
         assert_eq!(seg_d.count_ones(), 1);
-        println!("Segment[b]: {:07b}", seg_b);
-        println!("Segment[d]: {:07b}", seg_d);
 
         let six_pattern = patterns
             .iter()
@@ -180,24 +118,18 @@ fn run_p2(input: &Vec<String>) {
                 p.count_ones() == 6 &&
                     ((*p & one_pattern) != one_pattern)
             })
-            .unwrap()
-            .clone();
-        println!("Pattern[6]: {:07b}", six_pattern);
+            .copied()
+            .unwrap();
 
         let seg_f = six_pattern ^ (seg_a | seg_b | seg_d | seg_e | seg_g);
         assert_eq!(seg_f.count_ones(), 1);
-        println!("Segment[f]: {:07b}", seg_f);
 
         let seg_c = one_pattern ^ seg_f;
         assert_eq!(seg_c.count_ones(), 1);
-        println!("Segment[c]: {:07b}", seg_c);
 
         let zero_pattern = seg_a | seg_b | seg_c | seg_e | seg_f | seg_g;
         let two_pattern = seg_a | seg_c | seg_d | seg_e | seg_g;
         let five_pattern = seg_a | seg_b | seg_d | seg_f | seg_g;
-        println!("Pattern[0]: {:07b}", zero_pattern);
-        println!("Pattern[2]: {:07b}", two_pattern);
-        println!("Pattern[5]: {:07b}", five_pattern);
 
         assert_eq!(zero_pattern.count_ones(), 6);
         assert_eq!(one_pattern.count_ones(), 2);
@@ -211,8 +143,7 @@ fn run_p2(input: &Vec<String>) {
         assert_eq!(nine_pattern.count_ones(), 6);
 
         let mut number = 0;
-        for o in outputs {
-            println!("{:07b}", o);
+        for &o in outputs {
             //  I know, this is horrible
             let digit = if o == zero_pattern {
                 0
@@ -241,18 +172,50 @@ fn run_p2(input: &Vec<String>) {
             number = number*10 + digit;
         }
 
-        println!("=========> output number: {}", number);
-
         sum += number;
 
         // dbg!(patterns.borrow());
         // dbg!(<Vec<&str> as Borrow<[&str]>>::borrow(outputs.borrow()));
     }
 
-    println!("Total sum: {}", sum);
+    sum
 }
 
-fn main() {
-    let input = load("input.txt");
-    run_p2(input.borrow());
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<(Vec<u8>, Vec<u8>)>;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        input.lines()
+            .iter()
+            .map(|line| {
+                let (patterns, outputs) = line.split_once("|")?;
+                let patterns: Vec<u8> = patterns
+                    .split_whitespace()
+                    .map(|p| pattern_to_byte(line, p))
+                    .collect::<aoc_input::Result<_>>()?;
+                let outputs: Vec<u8> = outputs
+                    .split_whitespace()
+                    .map(|p| pattern_to_byte(line, p))
+                    .collect::<aoc_input::Result<_>>()?;
+
+                if patterns.len() != 10 || outputs.len() != 4 {
+                    let message = format!("expected 10 patterns and 4 outputs, found {} and {}",
+                                          patterns.len(), outputs.len());
+                    return Err(line.error_at(0, ErrorKind::Invalid(message)));
+                }
+
+                Ok((patterns, outputs))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        Ok(Some(run_p1(input).into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        Ok(Some(run_p2(input).into()))
+    }
 }
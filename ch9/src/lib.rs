@@ -0,0 +1,105 @@
+use std::collections::VecDeque;
+use aoc_input::{ErrorKind, Source};
+use common::{Grid, PartResult, Solution};
+
+#[allow(dead_code)]
+fn pretty_print_explored(explored: &Grid<bool>) {
+    print!("{}", explored);
+}
+
+fn find_basin_size(input: &Grid<i64>, point: (usize, usize)) -> usize {
+    let mut explored: Grid<bool> = Grid::filled(input.width(), input.height(), false);
+    let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+    let mut size = 0;
+
+    explored[point] = true;
+    queue.push_back(point);
+
+    while let Some((x, y)) = queue.pop_front() {
+        assert_ne!(input[(x, y)], 9);
+        size += 1;
+
+        // pretty_print_explored(&explored);
+
+        for other in input.neighbours4(x, y) {
+            if input[other] != 9 && !explored[other] {
+                explored[other] = true;
+                queue.push_back(other);
+            }
+        }
+    }
+
+    size
+}
+
+fn find_low_points(input: &Grid<i64>) -> Vec<(usize, usize)> {
+    input
+        .iter()
+        .filter(|&((x, y), &current)| {
+            input
+                .neighbours4(x, y)
+                .all(|other| input[other] > current)
+        })
+        .map(|(point, _)| point)
+        .collect()
+}
+
+fn run_part1(input: &Grid<i64>) -> i64 {
+    let mut risk_sum = 0;
+    for point in find_low_points(input) {
+        let risk = input[point] + 1;
+        risk_sum += risk;
+    }
+
+    risk_sum
+}
+
+fn run_part2(input: &Grid<i64>) -> usize {
+    let mut basins: Vec<usize> = vec![];
+    for point in find_low_points(input) {
+        let basin_size = find_basin_size(input, point);
+        basins.push(basin_size);
+    }
+
+    basins.sort_unstable();
+    basins
+        .into_iter()
+        .rev()
+        .take(3)
+        .reduce(|acc, f| {
+            acc * f
+        })
+        .unwrap()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Grid<i64>;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input> {
+        let grid = Grid::from_digits(input)?;
+
+        //  Every basin drains to a low point, and 9s belong to no basin
+        let lines = input.lines();
+        let low_points = find_low_points(&grid);
+        if low_points.is_empty() {
+            let kind = ErrorKind::Invalid("the heightmap has no low points".to_string());
+            return Err(lines[0].error_at(0, kind));
+        }
+        if let Some(&(x, y)) = low_points.iter().find(|&&point| grid[point] == 9) {
+            let kind = ErrorKind::Invalid("a low point of height 9 isn't in any basin".to_string());
+            return Err(lines[y].error_at(x + 1, kind));
+        }
+
+        Ok(grid)
+    }
+
+    fn part1(input: &Self::Input) -> PartResult {
+        Ok(Some(run_part1(input).into()))
+    }
+
+    fn part2(input: &Self::Input) -> PartResult {
+        Ok(Some(run_part2(input).into()))
+    }
+}
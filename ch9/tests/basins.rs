@@ -0,0 +1,22 @@
+use ch9::Day9;
+use common::Solution;
+
+#[test]
+fn parse_rejects_heightmaps_without_basins() {
+    let error = Day9::parse_str("9\n").unwrap_err();
+    assert_eq!(error.to_string(), "<input>:1:1: a low point of height 9 isn't in any basin");
+
+    let error = Day9::parse_str("11\n11\n").unwrap_err();
+    assert_eq!(error.to_string(), "<input>:1: the heightmap has no low points");
+}
+
+#[test]
+fn fewer_than_three_basins() {
+    let input = Day9::parse_str("1\n").unwrap();
+    assert_eq!(Day9::part1(&input), Ok(Some(2.into())));
+    assert_eq!(Day9::part2(&input), Ok(Some(1.into())));
+
+    let input = Day9::parse_str("0919\n").unwrap();
+    assert_eq!(Day9::part1(&input), Ok(Some(3.into())));
+    assert_eq!(Day9::part2(&input), Ok(Some(1.into())));
+}
@@ -0,0 +1,339 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use aoc_input::{Section, Source};
+
+const OFFSETS_4: [(i64, i64); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const OFFSETS_8: [(i64, i64); 8] = [
+    (-1, -1), (0, -1), (1, -1),
+    (-1, 0), (1, 0),
+    (-1, 1), (0, 1), (1, 1),
+];
+
+/// A rectangular grid of cells stored row by row, indexed by `(x, y)`
+/// with the origin in the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Wraps cells laid out row by row. Panics if they do not fill
+    /// whole rows of the given width.
+    pub fn new(cells: Vec<T>, width: usize) -> Grid<T> {
+        assert!(width > 0 && cells.len().is_multiple_of(width), "cells do not form whole rows");
+        let height = cells.len() / width;
+        Grid { cells, width, height }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(vec![value; width * height], width)
+    }
+
+    /// Reads a grid, mapping each character of the section to a cell
+    pub fn from_section<F: FnMut(char) -> Option<T>>(section: &Section, cell: F) -> aoc_input::Result<Grid<T>> {
+        let (cells, width) = section.grid(cell)?;
+        Ok(Grid::new(cells, width))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// Offset of a cell within `cells`
+    pub fn index_of(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
+    }
+
+    /// The `(x, y)` of an offset within `cells`
+    pub fn position_of(&self, idx: usize) -> (usize, usize) {
+        (idx % self.width, idx / self.width)
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && x < self.width as i64 && y < self.height as i64
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[self.index_of(x, y)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            let idx = self.index_of(x, y);
+            Some(&mut self.cells[idx])
+        } else {
+            None
+        }
+    }
+
+    /// Like `get`, but accepts coordinates that may lie off the grid
+    pub fn get_signed(&self, x: i64, y: i64) -> Option<&T> {
+        if self.contains(x, y) {
+            self.get(x as usize, y as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Every position in the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |idx| (idx % width, idx / width))
+    }
+
+    /// Every cell along with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The up to 4 orthogonally adjacent positions
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offset_positions(x, y, &OFFSETS_4)
+    }
+
+    /// The up to 8 adjacent positions, including diagonals
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offset_positions(x, y, &OFFSETS_8)
+    }
+
+    fn offset_positions(&self, x: usize, y: usize, offsets: &'static [(i64, i64)]) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        offsets
+            .iter()
+            .map(move |&(xoff, yoff)| (x as i64 + xoff, y as i64 + yoff))
+            .filter(move |&(x, y)| x >= 0 && y >= 0 && x < width && y < height)
+            .map(|(x, y)| (x as usize, y as usize))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: From<u8>> Grid<T> {
+    /// Reads a map of single digits, such as `2199943210`
+    pub fn from_digits(input: &Source) -> aoc_input::Result<Grid<T>> {
+        Grid::from_section(&input.section(), |ch| ch.to_digit(10).map(|digit| T::from(digit as u8)))
+    }
+}
+
+impl Grid<bool> {
+    /// Reads a map of `#` (set) and `.` (clear) cells
+    pub fn from_map(section: &Section) -> aoc_input::Result<Grid<bool>> {
+        Grid::from_section(section, |ch| match ch {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside the grid", x, y);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside the grid", x, y);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// How a single cell is drawn when a grid is displayed
+pub trait Cell {
+    fn symbol(&self) -> char;
+}
+
+impl Cell for bool {
+    fn symbol(&self) -> char {
+        if *self { '#' } else { '.' }
+    }
+}
+
+impl Cell for char {
+    fn symbol(&self) -> char {
+        *self
+    }
+}
+
+macro_rules! impl_cell_digit {
+    ($($t:ty),*) => {
+        $(
+            impl Cell for $t {
+                //  Anything that does not fit in a single digit is drawn as '+'
+                fn symbol(&self) -> char {
+                    u32::try_from(*self)
+                        .ok()
+                        .and_then(|digit| char::from_digit(digit, 10))
+                        .unwrap_or('+')
+                }
+            }
+        )*
+    };
+}
+
+impl_cell_digit!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+impl<T: Cell> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell.symbol())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_input::{ErrorKind, Source};
+    use super::Grid;
+
+    fn counting(width: usize, height: usize) -> Grid<usize> {
+        Grid::new((0..width * height).collect(), width)
+    }
+
+    fn sorted(positions: impl Iterator<Item = (usize, usize)>) -> Vec<(usize, usize)> {
+        let mut positions: Vec<(usize, usize)> = positions.collect();
+        positions.sort();
+        positions
+    }
+
+    #[test]
+    fn constructors() {
+        let grid = counting(3, 2);
+        assert_eq!((grid.width(), grid.height(), grid.len()), (3, 2, 6));
+        assert_eq!(grid.row(1), [3, 4, 5]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<usize>>(), [2, 5]);
+
+        let filled = Grid::filled(2, 4, 'x');
+        assert_eq!((filled.width(), filled.height()), (2, 4));
+        assert!(filled.cells().iter().all(|&cell| cell == 'x'));
+        assert_eq!(filled.to_string(), "xx\nxx\nxx\nxx\n");
+
+        let digits: Grid<u8> = Grid::from_digits(&Source::new("<input>", "123\n456\n")).unwrap();
+        assert_eq!(digits, Grid::new(vec![1, 2, 3, 4, 5, 6], 3));
+
+        let map = Grid::from_map(&Source::new("<input>", "#.\n.#\n").section()).unwrap();
+        assert_eq!(map.cells(), [true, false, false, true]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cells do not form whole rows")]
+    fn partial_rows() {
+        Grid::new(vec![1, 2, 3], 2);
+    }
+
+    #[test]
+    fn neighbours_at_corners_and_edges() {
+        let grid = counting(3, 3);
+        assert_eq!(sorted(grid.neighbours4(0, 0)), [(0, 1), (1, 0)]);
+        assert_eq!(sorted(grid.neighbours8(0, 0)), [(0, 1), (1, 0), (1, 1)]);
+        assert_eq!(sorted(grid.neighbours4(2, 2)), [(1, 2), (2, 1)]);
+        assert_eq!(sorted(grid.neighbours8(2, 2)), [(1, 1), (1, 2), (2, 1)]);
+
+        assert_eq!(sorted(grid.neighbours4(1, 0)), [(0, 0), (1, 1), (2, 0)]);
+        assert_eq!(sorted(grid.neighbours8(0, 1)), [(0, 0), (0, 2), (1, 0), (1, 1), (1, 2)]);
+        assert_eq!(grid.neighbours4(1, 1).count(), 4);
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+
+        //  A single row has nothing above or below
+        let row = counting(3, 1);
+        assert_eq!(sorted(row.neighbours8(1, 0)), [(0, 0), (2, 0)]);
+        assert_eq!(Grid::new(vec![0], 1).neighbours8(0, 0).count(), 0);
+    }
+
+    #[test]
+    fn indexing() {
+        let mut grid = counting(4, 3);
+        assert_eq!(grid[(3, 1)], 7);
+        assert_eq!(grid.index_of(3, 1), 7);
+        assert_eq!(grid.position_of(7), (3, 1));
+
+        grid[(0, 2)] = 100;
+        assert_eq!(grid.get(0, 2), Some(&100));
+        *grid.get_mut(1, 2).unwrap() = 101;
+        assert_eq!(grid.row(2), [100, 101, 10, 11]);
+
+        assert_eq!(grid.get(4, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(3, 2), Some(&11));
+        assert!(!grid.contains(0, -1));
+    }
+
+    #[test]
+    #[should_panic(expected = "(4, 0) is outside the grid")]
+    fn index_past_the_end_of_a_row() {
+        //  Would be the first cell of the next row if it wasn't checked
+        let _ = counting(4, 3)[(4, 0)];
+    }
+
+    #[test]
+    fn ragged_rows() {
+        let source = Source::new("<input>", "123\n45\n678\n");
+        let error = Grid::<u8>::from_digits(&source).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 0));
+        assert_eq!(error.kind, ErrorKind::RaggedRow { expected: 3, found: 2 });
+
+        let source = Source::new("<input>", "#.\n#x\n");
+        let error = Grid::from_map(&source.section()).unwrap_err();
+        assert_eq!((error.line, error.column, error.kind), (2, 2, ErrorKind::InvalidChar('x')));
+    }
+}
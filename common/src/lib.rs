@@ -0,0 +1,122 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+use aoc_input::{ErrorKind, Source};
+
+pub mod grid;
+pub mod point;
+
+pub use grid::{Cell, Grid};
+pub use point::Point;
+
+/// The result of running a single part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Anything that looks like an integer is read back as a number,
+    /// everything else is kept as text.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match i128::from_str(s) {
+            Ok(value) => Answer::Number(value),
+            Err(_) => Answer::Text(s.to_string()),
+        })
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<u128> for Answer {
+    /// Values past `i128::MAX` are kept as their digits, rather than
+    /// wrapping around
+    fn from(value: u128) -> Self {
+        match i128::try_from(value) {
+            Ok(value) => Answer::Number(value),
+            Err(_) => Answer::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Input that `parse` accepted, but that a part still has no answer for,
+/// such as one too large to work out. Lines and columns are as in
+/// `aoc_input::Error`, with 0 when the error is not tied to a position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ErrorKind,
+}
+
+impl PartError {
+    pub fn new(line: usize, column: usize, kind: ErrorKind) -> PartError {
+        PartError { line, column, kind }
+    }
+
+    /// The error as it is reported for the input it came from
+    pub fn in_file(self, file: &str) -> aoc_input::Error {
+        aoc_input::Error::new(file, self.line, self.column, self.kind)
+    }
+}
+
+/// What a part comes up with, which is `Ok(None)` if it has not been
+/// solved yet
+pub type PartResult = Result<Option<Answer>, PartError>;
+
+/// A single day of the calendar.
+///
+/// `parse` turns the raw puzzle input into whatever representation the
+/// day works on, which is then shared by both parts. Malformed input is
+/// rejected there, so the parts themselves can assume it is well-formed.
+/// The few things that can only be found out while solving a part are
+/// reported by it as a `PartError`.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &Source) -> aoc_input::Result<Self::Input>;
+
+    /// Parses input held in memory, which is reported as `<input>` in errors
+    fn parse_str(input: &str) -> aoc_input::Result<Self::Input> {
+        Self::parse(&Source::new("<input>", input))
+    }
+
+    fn part1(input: &Self::Input) -> PartResult;
+    fn part2(input: &Self::Input) -> PartResult;
+}
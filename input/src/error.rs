@@ -0,0 +1,72 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The file could not be read at all
+    Io(String),
+    /// The input ended where more data was expected
+    UnexpectedEnd,
+    /// A field could not be parsed as a number
+    InvalidNumber(String),
+    /// A character that is not allowed at this position
+    InvalidChar(char),
+    /// A literal token was missing
+    Expected(String),
+    /// A grid row with a different width than the first row
+    RaggedRow { expected: usize, found: usize },
+    /// Anything else a day wants to reject, with a description
+    Invalid(String),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Io(err) => write!(f, "{}", err),
+            ErrorKind::UnexpectedEnd => write!(f, "unexpected end of input"),
+            ErrorKind::InvalidNumber(field) => write!(f, "invalid number '{}'", field),
+            ErrorKind::InvalidChar(ch) => write!(f, "unexpected character '{}'", ch),
+            ErrorKind::Expected(token) => write!(f, "expected '{}'", token),
+            ErrorKind::RaggedRow { expected, found } => {
+                write!(f, "expected a row of width {}, found {}", expected, found)
+            }
+            ErrorKind::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+/// An error while reading puzzle input, pointing at the file, line
+/// and column it was found at. Lines and columns start at 1, a value
+/// of 0 means the error is not tied to a particular position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+    pub kind: ErrorKind,
+}
+
+impl Error {
+    pub fn new(file: &str, line: usize, column: usize, kind: ErrorKind) -> Error {
+        Error {
+            file: file.to_string(),
+            line,
+            column,
+            kind,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.file)?;
+        if self.line != 0 {
+            write!(f, ":{}", self.line)?;
+            if self.column != 0 {
+                write!(f, ":{}", self.column)?;
+            }
+        }
+        write!(f, ": {}", self.kind)
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,332 @@
+mod error;
+
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use std::str::FromStr;
+
+pub use error::{Error, ErrorKind};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The full text of a puzzle input, along with the name it is reported
+/// under in errors.
+#[derive(Debug, Clone)]
+pub struct Source {
+    name: String,
+    text: String,
+}
+
+impl Source {
+    pub fn new(name: &str, text: &str) -> Source {
+        Source {
+            name: name.to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Source> {
+        let path = path.as_ref();
+        let name = path.display().to_string();
+        match fs::read_to_string(path) {
+            Ok(text) => Ok(Source { name, text }),
+            Err(err) => Err(Error::new(&name, 0, 0, ErrorKind::Io(err.to_string()))),
+        }
+    }
+
+    /// Reads everything from a reader, such as stdin or a socket
+    pub fn read<R: Read>(name: &str, mut reader: R) -> Result<Source> {
+        let mut text = String::new();
+        match reader.read_to_string(&mut text) {
+            Ok(_) => Ok(Source::new(name, &text)),
+            Err(err) => Err(Error::new(name, 0, 0, ErrorKind::Io(err.to_string()))),
+        }
+    }
+
+    pub fn stdin() -> Result<Source> {
+        Source::read("<stdin>", io::stdin().lock())
+    }
+
+    /// Opens a path given on the command line, where `-` means stdin
+    pub fn from_arg(arg: &str) -> Result<Source> {
+        if arg == "-" {
+            Source::stdin()
+        } else {
+            Source::open(arg)
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// All lines of the input, with trailing blank lines dropped
+    pub fn lines(&self) -> Vec<Line<'_>> {
+        let mut lines: Vec<Line> = self.text
+            .lines()
+            .enumerate()
+            .map(|(idx, text)| Line {
+                file: &self.name,
+                number: idx + 1,
+                text,
+            })
+            .collect();
+
+        while lines.last().is_some_and(|line| line.text.trim().is_empty()) {
+            lines.pop();
+        }
+
+        lines
+    }
+
+    /// The whole input as a single section
+    pub fn section(&self) -> Section<'_> {
+        Section {
+            file: &self.name,
+            lines: self.lines(),
+        }
+    }
+
+    /// Groups of lines separated by one or more blank lines
+    pub fn sections(&self) -> Vec<Section<'_>> {
+        let mut sections: Vec<Section> = vec![];
+        let mut current: Vec<Line> = vec![];
+        for line in self.lines() {
+            if line.text.trim().is_empty() {
+                if !current.is_empty() {
+                    sections.push(Section { file: &self.name, lines: current });
+                    current = vec![];
+                }
+                continue;
+            }
+            current.push(line);
+        }
+        if !current.is_empty() {
+            sections.push(Section { file: &self.name, lines: current });
+        }
+
+        sections
+    }
+
+    /// Reads a comma-separated list from the first line
+    pub fn comma_list<T: FromStr>(&self) -> Result<Vec<T>> {
+        self.section().line(0)?.comma_list()
+    }
+
+    /// Reads a rectangular grid of single digits
+    pub fn digit_grid<T: From<u8>>(&self) -> Result<(Vec<T>, usize)> {
+        self.section().digit_grid()
+    }
+
+    /// Reads a rectangular grid, mapping each character to a cell
+    pub fn grid<T, F: FnMut(char) -> Option<T>>(&self, cell: F) -> Result<(Vec<T>, usize)> {
+        self.section().grid(cell)
+    }
+}
+
+/// A run of consecutive non-blank lines
+#[derive(Debug, Clone)]
+pub struct Section<'a> {
+    file: &'a str,
+    lines: Vec<Line<'a>>,
+}
+
+impl<'a> Section<'a> {
+    pub fn lines(&self) -> &[Line<'a>] {
+        &self.lines
+    }
+
+    /// The line at the given index within the section
+    pub fn line(&self, index: usize) -> Result<Line<'a>> {
+        match self.lines.get(index) {
+            Some(line) => Ok(*line),
+            None => Err(self.end_error()),
+        }
+    }
+
+    /// An error pointing just past the last line of the section
+    pub fn end_error(&self) -> Error {
+        let line = self.lines.last().map_or(1, |line| line.number + 1);
+        Error::new(self.file, line, 0, ErrorKind::UnexpectedEnd)
+    }
+
+    pub fn digit_grid<T: From<u8>>(&self) -> Result<(Vec<T>, usize)> {
+        self.grid(|ch| ch.to_digit(10).map(|digit| T::from(digit as u8)))
+    }
+
+    pub fn grid<T, F: FnMut(char) -> Option<T>>(&self, mut cell: F) -> Result<(Vec<T>, usize)> {
+        let width = self.line(0)?.text.chars().count();
+
+        let mut cells: Vec<T> = Vec::with_capacity(width * self.lines.len());
+        for line in self.lines.iter() {
+            let found = line.text.chars().count();
+            if found != width {
+                return Err(line.error_at(0, ErrorKind::RaggedRow { expected: width, found }));
+            }
+
+            for (idx, ch) in line.text.chars().enumerate() {
+                match cell(ch) {
+                    Some(value) => cells.push(value),
+                    None => return Err(line.error_at(idx + 1, ErrorKind::InvalidChar(ch))),
+                }
+            }
+        }
+
+        Ok((cells, width))
+    }
+}
+
+/// A single line of input, which remembers where it came from
+#[derive(Debug, Copy, Clone)]
+pub struct Line<'a> {
+    file: &'a str,
+    number: usize,
+    text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    /// Column (starting at 1) of a slice borrowed from this line's text
+    pub fn column_of(&self, field: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (field.as_ptr() as usize).wrapping_sub(start);
+        if offset > self.text.len() {
+            return 0;
+        }
+
+        self.text[..offset].chars().count() + 1
+    }
+
+    pub fn error_at(&self, column: usize, kind: ErrorKind) -> Error {
+        Error::new(self.file, self.number, column, kind)
+    }
+
+    /// An error pointing at a slice borrowed from this line's text
+    pub fn error(&self, field: &str, kind: ErrorKind) -> Error {
+        self.error_at(self.column_of(field), kind)
+    }
+
+    /// Parses the whole line, ignoring surrounding whitespace
+    pub fn parse<T: FromStr>(&self) -> Result<T> {
+        self.field(self.text.trim())
+    }
+
+    /// Parses a slice borrowed from this line's text
+    pub fn field<T: FromStr>(&self, field: &str) -> Result<T> {
+        T::from_str(field)
+            .map_err(|_| self.error(field, ErrorKind::InvalidNumber(field.to_string())))
+    }
+
+    pub fn comma_list<T: FromStr>(&self) -> Result<Vec<T>> {
+        self.text
+            .trim()
+            .split(',')
+            .map(|field| self.field(field.trim()))
+            .collect()
+    }
+
+    /// Parses every whitespace-separated field of the line
+    pub fn fields<T: FromStr>(&self) -> Result<Vec<T>> {
+        self.text
+            .split_whitespace()
+            .map(|field| self.field(field))
+            .collect()
+    }
+
+    pub fn split_once(&self, delimiter: &str) -> Result<(&'a str, &'a str)> {
+        self.text
+            .split_once(delimiter)
+            .ok_or_else(|| self.end_error(ErrorKind::Expected(delimiter.to_string())))
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<&'a str> {
+        self.text
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error_at(1, ErrorKind::Expected(prefix.to_string())))
+    }
+
+    /// An error pointing just past the end of the line
+    pub fn end_error(&self, kind: ErrorKind) -> Error {
+        self.error_at(self.text.chars().count() + 1, kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, ErrorKind, Source};
+
+    fn source(text: &str) -> Source {
+        Source::new("input.txt", text)
+    }
+
+    #[test]
+    fn error_positions() {
+        let error = Error::new("input.txt", 3, 7, ErrorKind::InvalidChar('?'));
+        assert_eq!(error.to_string(), "input.txt:3:7: unexpected character '?'");
+        let error = Error::new("input.txt", 3, 0, ErrorKind::UnexpectedEnd);
+        assert_eq!(error.to_string(), "input.txt:3: unexpected end of input");
+        let error = Error::new("input.txt", 0, 0, ErrorKind::Invalid("no start".to_string()));
+        assert_eq!(error.to_string(), "input.txt: no start");
+
+        //  Columns count characters rather than bytes
+        let input = source("1 2\nλ -> 42x\n");
+        let line = input.lines()[1];
+        let error = line.field::<u32>(&line.text()[6..]).unwrap_err();
+        assert_eq!(error.to_string(), "input.txt:2:6: invalid number '42x'");
+        assert_eq!(line.end_error(ErrorKind::UnexpectedEnd).column, 9);
+        assert_eq!(line.split_once(",").unwrap_err().to_string(), "input.txt:2:9: expected ','");
+        assert_eq!(line.strip_prefix("x").unwrap_err().column, 1);
+    }
+
+    #[test]
+    fn sections() {
+        let input = source("a\nb\n\n\n  \nc\n\nd\ne\n\n\n");
+        let sections = input.sections();
+        let texts: Vec<Vec<&str>> = sections
+            .iter()
+            .map(|section| section.lines().iter().map(|line| line.text()).collect())
+            .collect();
+        assert_eq!(texts, [vec!["a", "b"], vec!["c"], vec!["d", "e"]]);
+
+        //  Lines keep their numbers within the whole file
+        assert_eq!(sections[2].line(1).unwrap().number(), 9);
+        assert_eq!(sections[1].line(1).unwrap_err(), Error::new("input.txt", 7, 0, ErrorKind::UnexpectedEnd));
+
+        //  Trailing blank lines are dropped, but not ones in between
+        assert_eq!(input.lines().len(), 9);
+        assert!(source("\n\n").sections().is_empty());
+    }
+
+    #[test]
+    fn bad_fields() {
+        let input = source("3,4,x5\n1 2  three\n");
+        let lines = input.lines();
+
+        let error = lines[0].comma_list::<u8>().unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+        assert_eq!(error.kind, ErrorKind::InvalidNumber("x5".to_string()));
+
+        let error = lines[1].fields::<i32>().unwrap_err();
+        assert_eq!(error.to_string(), "input.txt:2:6: invalid number 'three'");
+
+        let error = lines[1].parse::<u64>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = source("300,1\n").comma_list::<u8>().unwrap_err();
+        assert_eq!(error.to_string(), "input.txt:1:1: invalid number '300'");
+
+        let error = source("").comma_list::<u8>().unwrap_err();
+        assert_eq!(error, Error::new("input.txt", 1, 0, ErrorKind::UnexpectedEnd));
+    }
+}